@@ -0,0 +1,118 @@
+//! RPC interface for the account mapping pallet.
+
+use std::{marker::PhantomData, str::FromStr, sync::Arc};
+
+use codec::Codec;
+use jsonrpsee::{
+	core::RpcResult,
+	proc_macros::rpc,
+	types::error::{CallError, ErrorObject},
+};
+use serde::{Deserialize, Serialize};
+use sp_api::ProvideRuntimeApi;
+use sp_blockchain::HeaderBackend;
+use sp_core::{crypto::Ss58Codec, H160};
+use sp_runtime::traits::Block as BlockT;
+
+pub use pallet_account_mapping_runtime_api::AccountMappingApi as AccountMappingRuntimeApi;
+
+/// Both sides of an account mapping.
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MappingInfo<AccountId> {
+	/// The native account.
+	pub account: AccountId,
+	/// The EVM address funds of `account` can be spent from, if there is one.
+	pub evm_address: Option<H160>,
+	/// Whether the two were explicitly linked on chain.
+	pub linked: bool,
+}
+
+#[rpc(client, server)]
+pub trait AccountMappingApi<BlockHash, AccountId> {
+	/// Look up the mapping of an EVM address (`0x`-prefixed hex) or SS58 address.
+	#[method(name = "account_mapping")]
+	fn account_mapping(
+		&self,
+		address: String,
+		at: Option<BlockHash>,
+	) -> RpcResult<MappingInfo<AccountId>>;
+}
+
+/// Error type of this RPC api.
+pub enum Error {
+	/// The given address is neither an EVM nor an SS58 address.
+	InvalidAddress,
+	/// The call to runtime failed.
+	RuntimeError,
+}
+
+impl From<Error> for i32 {
+	fn from(e: Error) -> i32 {
+		match e {
+			Error::InvalidAddress => 1,
+			Error::RuntimeError => 2,
+		}
+	}
+}
+
+/// Provides RPC methods to query the account mapping registry.
+pub struct AccountMapping<C, B> {
+	client: Arc<C>,
+	_marker: PhantomData<B>,
+}
+
+impl<C, B> AccountMapping<C, B> {
+	/// Creates a new instance of the AccountMapping Rpc helper.
+	pub fn new(client: Arc<C>) -> Self {
+		Self { client, _marker: Default::default() }
+	}
+}
+
+impl<C, Block, AccountId> AccountMappingApiServer<<Block as BlockT>::Hash, AccountId>
+	for AccountMapping<C, Block>
+where
+	Block: BlockT,
+	C: ProvideRuntimeApi<Block> + HeaderBackend<Block> + Send + Sync + 'static,
+	C::Api: AccountMappingRuntimeApi<Block, AccountId>,
+	AccountId: Codec + Ss58Codec + Clone + Serialize + Send + Sync + 'static,
+{
+	fn account_mapping(
+		&self,
+		address: String,
+		at: Option<Block::Hash>,
+	) -> RpcResult<MappingInfo<AccountId>> {
+		let api = self.client.runtime_api();
+		let at = at.unwrap_or_else(|| self.client.info().best_hash);
+		let runtime_error = |e: sp_api::ApiError| {
+			CallError::Custom(ErrorObject::owned(
+				Error::RuntimeError.into(),
+				"Unable to query account mapping.",
+				Some(e.to_string()),
+			))
+		};
+
+		if address.starts_with("0x") {
+			let evm_address = H160::from_str(&address).map_err(|e| {
+				CallError::Custom(ErrorObject::owned(
+					Error::InvalidAddress.into(),
+					"Invalid EVM address.",
+					Some(e.to_string()),
+				))
+			})?;
+			let (account, linked) = api.native_account(at, evm_address).map_err(runtime_error)?;
+			Ok(MappingInfo { account, evm_address: Some(evm_address), linked })
+		} else {
+			let account = AccountId::from_ss58check(&address).map_err(|e| {
+				CallError::Custom(ErrorObject::owned(
+					Error::InvalidAddress.into(),
+					"Invalid SS58 address.",
+					Some(format!("{:?}", e)),
+				))
+			})?;
+			let evm_address =
+				api.evm_address(at, account.clone()).map_err(runtime_error)?;
+			Ok(MappingInfo { account, evm_address, linked: evm_address.is_some() })
+		}
+	}
+}
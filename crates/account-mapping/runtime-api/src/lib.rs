@@ -0,0 +1,20 @@
+//! Runtime API definition for the account mapping pallet.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use codec::Codec;
+use sp_core::H160;
+
+sp_api::decl_runtime_apis! {
+	pub trait AccountMappingApi<AccountId>
+		where
+			AccountId: Codec,
+	{
+		/// Returns the native account holding the funds of `address`, and whether it was
+		/// explicitly linked to it.
+		fn native_account(address: H160) -> (AccountId, bool);
+
+		/// Returns the EVM address linked to `account`, if any.
+		fn evm_address(account: AccountId) -> Option<H160>;
+	}
+}
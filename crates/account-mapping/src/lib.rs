@@ -0,0 +1,372 @@
+//! # Account Mapping Pallet
+//!
+//! A registry linking EVM addresses (`H160`) to native accounts.
+//!
+//! Without an explicit link the runtime derives the native account of an EVM address with a
+//! fixed [`Config::FallbackMapping`], which nobody holds the native key of. With
+//! [`Pallet::claim_account`], a native account links itself to an EVM address by submitting
+//! a signature of that EVM key over its own account id. From then on both keys control the
+//! very same balance:
+//!
+//! - [`LinkedAddressMapping`] resolves EVM addresses through the registry and is meant to be
+//!   used as `pallet_evm::Config::AddressMapping` (and wherever else the runtime maps an `H160`
+//!   to an account).
+//! - [`EnsureAddressLinked`] only lets the linked native account act on behalf of a linked EVM
+//!   address.
+//!
+//! Links are one-to-one and permanent. The transferable funds of the fallback account of an EVM
+//! address are moved to the native account when the link is made. Stakes and vesting schedules
+//! cannot be moved along, so an EVM address can only be linked once its fallback account has
+//! none, see [`Config::StakedOrVesting`].
+//!
+//! Users who keep the two apart can move funds between their native account and the fallback
+//! account of their EVM address with [`Pallet::transfer_to_evm`] and
+//! [`Pallet::transfer_from_evm`], or from the EVM side through [`AccountMappingPrecompile`].
+//! Moving funds out of the fallback account requires a signature of the EVM key over
+//! [`Pallet::transfer_payload`], and moving funds out of a native account from the EVM side
+//! requires an sr25519 signature of the native key over the same payload.
+//!
+//! The message signed by the EVM key follows `personal_sign` (EIP-191):
+//! `"\x19Ethereum Signed Message:\n" ++ len(payload) ++ payload`, where `payload` is
+//! [`Config::ClaimPrefix`] followed by the SCALE-encoded genesis hash of the chain and native
+//! account id. Covering the genesis hash keeps signatures made for one chain, e.g. a testnet,
+//! from being replayed on another.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+#[cfg(test)]
+mod mock;
+mod precompile;
+#[cfg(test)]
+mod tests;
+
+use codec::{Decode, Encode, MaxEncodedLen};
+use frame_support::{dispatch::RawOrigin, RuntimeDebug};
+use pallet_evm::{AddressMapping, EnsureAddressOrigin};
+use scale_info::TypeInfo;
+use sp_core::H160;
+use sp_std::{marker::PhantomData, prelude::*};
+
+pub use pallet::*;
+pub use precompile::AccountMappingPrecompile;
+
+/// An ECDSA signature with recovery id, as produced by Ethereum wallets.
+#[derive(Clone, Copy, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct EcdsaSignature(pub [u8; 65]);
+
+#[frame_support::pallet]
+pub mod pallet {
+	use super::*;
+	use frame_support::{
+		pallet_prelude::*,
+		traits::{fungible, Contains, Currency, ExistenceRequirement},
+	};
+	use frame_system::pallet_prelude::*;
+	use sp_runtime::traits::{SaturatedConversion, Zero};
+
+	pub type BalanceOf<T> =
+		<<T as Config>::Currency as Currency<<T as frame_system::Config>::AccountId>>::Balance;
+
+	/// Direction of a transfer between a native account and the fallback account of an EVM
+	/// address, as covered by the signature authorizing it.
+	#[derive(Clone, Copy, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo)]
+	pub enum TransferDirection {
+		/// From the native account to the fallback account.
+		ToEvm,
+		/// From the fallback account to the native account.
+		FromEvm,
+	}
+
+	#[pallet::pallet]
+	pub struct Pallet<T>(_);
+
+	#[pallet::config]
+	pub trait Config: frame_system::Config {
+		/// The overarching event type.
+		type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+
+		/// The currency funds of the fallback account are moved with when linking.
+		type Currency: Currency<Self::AccountId> + fungible::Inspect<Self::AccountId>;
+
+		/// How EVM addresses that are not linked map to native accounts.
+		type FallbackMapping: AddressMapping<Self::AccountId>;
+
+		/// Prefix of the payload an EVM key signs to link itself to a native account.
+		#[pallet::constant]
+		type ClaimPrefix: Get<&'static [u8]>;
+
+		/// Accounts with a stake or a vesting schedule. Neither is moved when linking, so an EVM
+		/// address cannot be linked while its fallback account is one of them.
+		type StakedOrVesting: Contains<Self::AccountId>;
+	}
+
+	/// The native account each linked EVM address belongs to.
+	#[pallet::storage]
+	#[pallet::getter(fn native_account)]
+	pub type NativeAccounts<T: Config> = StorageMap<_, Blake2_128Concat, H160, T::AccountId>;
+
+	/// The EVM address each linked native account is reachable from.
+	#[pallet::storage]
+	#[pallet::getter(fn evm_address)]
+	pub type EvmAddresses<T: Config> = StorageMap<_, Blake2_128Concat, T::AccountId, H160>;
+
+	/// Number of signed transfers made for each EVM address, preventing signature replay.
+	#[pallet::storage]
+	#[pallet::getter(fn transfer_nonce)]
+	pub type TransferNonces<T: Config> = StorageMap<_, Blake2_128Concat, H160, u64, ValueQuery>;
+
+	#[pallet::event]
+	#[pallet::generate_deposit(pub(super) fn deposit_event)]
+	pub enum Event<T: Config> {
+		/// `account` was linked to `evm_address`.
+		AccountLinked { account: T::AccountId, evm_address: H160 },
+		/// `amount` was moved from `account` to the fallback account of `evm_address`.
+		TransferredToEvm { account: T::AccountId, evm_address: H160, amount: BalanceOf<T> },
+		/// `amount` was moved from the fallback account of `evm_address` to `account`.
+		TransferredFromEvm { account: T::AccountId, evm_address: H160, amount: BalanceOf<T> },
+	}
+
+	#[pallet::error]
+	pub enum Error<T> {
+		/// The native account is already linked to an EVM address.
+		AccountAlreadyLinked,
+		/// The EVM address is already linked to a native account.
+		EvmAddressAlreadyLinked,
+		/// The signature was not made by the key of the given EVM address.
+		InvalidSignature,
+		/// The EVM address is linked, its funds already are those of the native account.
+		EvmAddressLinked,
+		/// The fallback account of the EVM address has a stake or a vesting schedule, which
+		/// would be left behind. Unbond it or wait until it is fully vested first.
+		FallbackStakedOrVesting,
+	}
+
+	#[pallet::call]
+	impl<T: Config> Pallet<T> {
+		/// Link the signing account to `evm_address`.
+		///
+		/// `signature` must be a `personal_sign` signature of the EVM key over
+		/// [`Pallet::claim_payload`] of the signing account. The transferable balance of the
+		/// fallback account of `evm_address` is moved to the signing account. Fails while the
+		/// fallback account has a stake or a vesting schedule.
+		///
+		/// Emits `AccountLinked`.
+		#[pallet::call_index(0)]
+		#[pallet::weight(T::DbWeight::get().reads_writes(8, 4).saturating_add(Weight::from_ref_time(60_000_000)))]
+		pub fn claim_account(
+			origin: OriginFor<T>,
+			evm_address: H160,
+			signature: EcdsaSignature,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			ensure!(!EvmAddresses::<T>::contains_key(&who), Error::<T>::AccountAlreadyLinked);
+			ensure!(
+				!NativeAccounts::<T>::contains_key(evm_address),
+				Error::<T>::EvmAddressAlreadyLinked
+			);
+			ensure!(
+				Self::recover_signer(&Self::claim_payload(&who), &signature) == Some(evm_address),
+				Error::<T>::InvalidSignature
+			);
+
+			let fallback = T::FallbackMapping::into_account_id(evm_address);
+			if fallback != who {
+				ensure!(
+					!T::StakedOrVesting::contains(&fallback),
+					Error::<T>::FallbackStakedOrVesting
+				);
+				// Funds under other locks, e.g. of a vote, stay with the fallback account.
+				let balance: BalanceOf<T> =
+					<T::Currency as fungible::Inspect<_>>::reducible_balance(&fallback, false)
+						.saturated_into::<u128>()
+						.saturated_into();
+				T::Currency::transfer(&fallback, &who, balance, ExistenceRequirement::AllowDeath)?;
+			}
+
+			NativeAccounts::<T>::insert(evm_address, &who);
+			EvmAddresses::<T>::insert(&who, evm_address);
+			Self::deposit_event(Event::<T>::AccountLinked { account: who, evm_address });
+			Ok(())
+		}
+
+		/// Move `amount` from the signing account to the fallback account of `evm_address`,
+		/// where it can be spent with the EVM key.
+		///
+		/// Emits `TransferredToEvm`.
+		#[pallet::call_index(1)]
+		#[pallet::weight(T::DbWeight::get().reads_writes(3, 2).saturating_add(Weight::from_ref_time(50_000_000)))]
+		pub fn transfer_to_evm(
+			origin: OriginFor<T>,
+			evm_address: H160,
+			#[pallet::compact] amount: BalanceOf<T>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			Self::do_transfer_to_evm(&who, evm_address, amount)
+		}
+
+		/// Move `amount` from the fallback account of `evm_address` to the signing account.
+		///
+		/// `signature` must be a `personal_sign` signature of the EVM key over
+		/// [`Pallet::transfer_payload`] for this transfer.
+		///
+		/// Emits `TransferredFromEvm`.
+		#[pallet::call_index(2)]
+		#[pallet::weight(T::DbWeight::get().reads_writes(4, 3).saturating_add(Weight::from_ref_time(110_000_000)))]
+		pub fn transfer_from_evm(
+			origin: OriginFor<T>,
+			evm_address: H160,
+			#[pallet::compact] amount: BalanceOf<T>,
+			signature: EcdsaSignature,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			let payload =
+				Self::transfer_payload(TransferDirection::FromEvm, &who, evm_address, amount);
+			ensure!(
+				Self::recover_signer(&payload, &signature) == Some(evm_address),
+				Error::<T>::InvalidSignature
+			);
+			TransferNonces::<T>::mutate(evm_address, |nonce| *nonce = nonce.saturating_add(1));
+
+			Self::do_transfer_from_evm(evm_address, &who, amount)
+		}
+	}
+
+	impl<T: Config> Pallet<T> {
+		/// The native account holding the funds of `address`, whether linked or not.
+		pub fn account_of(address: H160) -> T::AccountId {
+			NativeAccounts::<T>::get(address)
+				.unwrap_or_else(|| T::FallbackMapping::into_account_id(address))
+		}
+
+		/// The payload the EVM key has to sign to be linked to `who`.
+		pub fn claim_payload(who: &T::AccountId) -> Vec<u8> {
+			let mut payload = T::ClaimPrefix::get().to_vec();
+			payload.extend((Self::genesis_hash(), who).encode());
+			payload
+		}
+
+		/// The payload signed to authorize moving `amount` between `account` and the fallback
+		/// account of `evm_address` in `direction`. It covers the genesis hash of the chain and
+		/// the current transfer nonce of `evm_address`, so every signature can only be used once
+		/// and on this chain only.
+		pub fn transfer_payload(
+			direction: TransferDirection,
+			account: &T::AccountId,
+			evm_address: H160,
+			amount: BalanceOf<T>,
+		) -> Vec<u8> {
+			let mut payload = T::ClaimPrefix::get().to_vec();
+			payload.extend(
+				(
+					Self::genesis_hash(),
+					direction,
+					account,
+					evm_address,
+					amount,
+					TransferNonces::<T>::get(evm_address),
+				)
+					.encode(),
+			);
+			payload
+		}
+
+		/// The hash of the genesis block, identifying the chain signed payloads are meant for.
+		fn genesis_hash() -> T::Hash {
+			frame_system::Pallet::<T>::block_hash(T::BlockNumber::zero())
+		}
+
+		pub(crate) fn do_transfer_to_evm(
+			who: &T::AccountId,
+			evm_address: H160,
+			amount: BalanceOf<T>,
+		) -> DispatchResult {
+			ensure!(!NativeAccounts::<T>::contains_key(evm_address), Error::<T>::EvmAddressLinked);
+
+			let fallback = T::FallbackMapping::into_account_id(evm_address);
+			T::Currency::transfer(who, &fallback, amount, ExistenceRequirement::AllowDeath)?;
+			Self::deposit_event(Event::<T>::TransferredToEvm {
+				account: who.clone(),
+				evm_address,
+				amount,
+			});
+			Ok(())
+		}
+
+		pub(crate) fn do_transfer_from_evm(
+			evm_address: H160,
+			who: &T::AccountId,
+			amount: BalanceOf<T>,
+		) -> DispatchResult {
+			ensure!(!NativeAccounts::<T>::contains_key(evm_address), Error::<T>::EvmAddressLinked);
+
+			let fallback = T::FallbackMapping::into_account_id(evm_address);
+			T::Currency::transfer(&fallback, who, amount, ExistenceRequirement::AllowDeath)?;
+			Self::deposit_event(Event::<T>::TransferredFromEvm {
+				account: who.clone(),
+				evm_address,
+				amount,
+			});
+			Ok(())
+		}
+
+		/// The EVM address that signed `payload` with `personal_sign`, if the signature is valid.
+		pub fn recover_signer(payload: &[u8], signature: &EcdsaSignature) -> Option<H160> {
+			let mut message = b"\x19Ethereum Signed Message:\n".to_vec();
+			message.extend(decimal(payload.len()));
+			message.extend(payload);
+			let hash = sp_io::hashing::keccak_256(&message);
+
+			let public = sp_io::crypto::secp256k1_ecdsa_recover(&signature.0, &hash).ok()?;
+			Some(H160::from_slice(&sp_io::hashing::keccak_256(&public)[12..]))
+		}
+	}
+}
+
+/// ASCII decimal representation of `n`, as used in the `personal_sign` message prefix.
+fn decimal(mut n: usize) -> Vec<u8> {
+	let mut digits = Vec::new();
+	loop {
+		digits.push(b'0' + (n % 10) as u8);
+		n /= 10;
+		if n == 0 {
+			break
+		}
+	}
+	digits.reverse();
+	digits
+}
+
+/// Maps EVM addresses through the registry, falling back to [`Config::FallbackMapping`] for
+/// addresses that are not linked.
+pub struct LinkedAddressMapping<T>(PhantomData<T>);
+
+impl<T: Config> AddressMapping<T::AccountId> for LinkedAddressMapping<T> {
+	fn into_account_id(address: H160) -> T::AccountId {
+		Pallet::<T>::account_of(address)
+	}
+}
+
+/// Only the linked native account may act on behalf of a linked EVM address. Addresses that
+/// are not linked are checked by `F`.
+pub struct EnsureAddressLinked<T, F>(PhantomData<(T, F)>);
+
+impl<T, F, OuterOrigin> EnsureAddressOrigin<OuterOrigin> for EnsureAddressLinked<T, F>
+where
+	T: Config,
+	F: EnsureAddressOrigin<OuterOrigin, Success = T::AccountId>,
+	OuterOrigin: Into<Result<RawOrigin<T::AccountId>, OuterOrigin>> + From<RawOrigin<T::AccountId>>,
+{
+	type Success = T::AccountId;
+
+	fn try_address_origin(address: &H160, origin: OuterOrigin) -> Result<T::AccountId, OuterOrigin> {
+		match NativeAccounts::<T>::get(address) {
+			Some(account) => origin.into().and_then(|o| match o {
+				RawOrigin::Signed(who) if who == account => Ok(who),
+				r => Err(OuterOrigin::from(r)),
+			}),
+			None => F::try_address_origin(address, origin),
+		}
+	}
+}
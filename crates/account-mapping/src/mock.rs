@@ -0,0 +1,158 @@
+//! Test utilities
+
+use crate::{self as pallet_account_mapping, *};
+use frame_support::{
+	parameter_types,
+	traits::{ConstU32, ConstU64, Contains, GenesisBuild},
+	weights::constants::RocksDbWeight,
+};
+use sp_core::{ecdsa, Pair, H256};
+use sp_runtime::{testing::Header, traits::IdentityLookup};
+
+pub(crate) type AccountId = u64;
+pub(crate) type Balance = u128;
+
+type UncheckedExtrinsic = frame_system::mocking::MockUncheckedExtrinsic<Test>;
+type Block = frame_system::mocking::MockBlock<Test>;
+
+frame_support::construct_runtime!(
+	pub enum Test where
+		Block = Block,
+		NodeBlock = Block,
+		UncheckedExtrinsic = UncheckedExtrinsic,
+	{
+		System: frame_system,
+		Balances: pallet_balances,
+		AccountMapping: pallet_account_mapping,
+	}
+);
+
+impl frame_system::Config for Test {
+	type BaseCallFilter = frame_support::traits::Everything;
+	type BlockWeights = ();
+	type BlockLength = ();
+	type DbWeight = RocksDbWeight;
+	type RuntimeOrigin = RuntimeOrigin;
+	type Index = u64;
+	type BlockNumber = u64;
+	type RuntimeCall = RuntimeCall;
+	type Hash = H256;
+	type Hashing = ::sp_runtime::traits::BlakeTwo256;
+	type AccountId = AccountId;
+	type Lookup = IdentityLookup<Self::AccountId>;
+	type Header = Header;
+	type RuntimeEvent = RuntimeEvent;
+	type BlockHashCount = ConstU64<250>;
+	type Version = ();
+	type PalletInfo = PalletInfo;
+	type AccountData = pallet_balances::AccountData<Balance>;
+	type OnNewAccount = ();
+	type OnKilledAccount = ();
+	type SystemWeightInfo = ();
+	type SS58Prefix = ();
+	type OnSetCode = ();
+	type MaxConsumers = ConstU32<16>;
+}
+
+parameter_types! {
+	pub static ExistentialDeposit: Balance = 1;
+	pub const ClaimPrefix: &'static [u8] = b"Link to account:";
+	pub static StakedOrVestingAccounts: Vec<AccountId> = vec![];
+}
+
+impl pallet_balances::Config for Test {
+	type MaxLocks = ConstU32<1024>;
+	type MaxReserves = ();
+	type ReserveIdentifier = [u8; 8];
+	type Balance = Balance;
+	type RuntimeEvent = RuntimeEvent;
+	type DustRemoval = ();
+	type ExistentialDeposit = ExistentialDeposit;
+	type AccountStore = System;
+	type WeightInfo = ();
+}
+
+/// Unlinked addresses map to the account with the same low 8 bytes.
+pub struct LowU64AddressMapping;
+impl AddressMapping<AccountId> for LowU64AddressMapping {
+	fn into_account_id(address: H160) -> AccountId {
+		address.to_low_u64_be()
+	}
+}
+
+/// Unlinked addresses may be used by the account with the same low 8 bytes.
+pub struct EnsureLowU64;
+impl<OuterOrigin> EnsureAddressOrigin<OuterOrigin> for EnsureLowU64
+where
+	OuterOrigin: Into<Result<RawOrigin<AccountId>, OuterOrigin>> + From<RawOrigin<AccountId>>,
+{
+	type Success = AccountId;
+
+	fn try_address_origin(address: &H160, origin: OuterOrigin) -> Result<AccountId, OuterOrigin> {
+		origin.into().and_then(|o| match o {
+			RawOrigin::Signed(who) if who == address.to_low_u64_be() => Ok(who),
+			r => Err(OuterOrigin::from(r)),
+		})
+	}
+}
+
+pub struct StakedOrVesting;
+impl Contains<AccountId> for StakedOrVesting {
+	fn contains(who: &AccountId) -> bool {
+		StakedOrVestingAccounts::get().contains(who)
+	}
+}
+
+impl Config for Test {
+	type RuntimeEvent = RuntimeEvent;
+	type Currency = Balances;
+	type FallbackMapping = LowU64AddressMapping;
+	type ClaimPrefix = ClaimPrefix;
+	type StakedOrVesting = StakedOrVesting;
+}
+
+/// The EVM key with the given seed.
+pub fn evm_key(seed: &str) -> ecdsa::Pair {
+	ecdsa::Pair::from_string(&format!("//{}", seed), None).unwrap()
+}
+
+/// The EVM address of `pair`, recovered from a signature as a wallet would derive it.
+pub fn evm_address(pair: &ecdsa::Pair) -> H160 {
+	let hash = [7u8; 32];
+	let mut signature = [0u8; 65];
+	signature.copy_from_slice(pair.sign_prehashed(&hash).as_ref());
+	let public = sp_io::crypto::secp256k1_ecdsa_recover(&signature, &hash).unwrap();
+	H160::from_slice(&sp_io::hashing::keccak_256(&public)[12..])
+}
+
+/// A `personal_sign` signature of `pair` linking it to `who`.
+pub fn claim_signature(pair: &ecdsa::Pair, who: AccountId) -> EcdsaSignature {
+	personal_sign(pair, AccountMapping::claim_payload(&who))
+}
+
+/// A `personal_sign` signature of `pair` moving `amount` from its fallback account to `who`.
+pub fn transfer_signature(pair: &ecdsa::Pair, who: AccountId, amount: Balance) -> EcdsaSignature {
+	personal_sign(
+		pair,
+		AccountMapping::transfer_payload(TransferDirection::FromEvm, &who, evm_address(pair), amount),
+	)
+}
+
+fn personal_sign(pair: &ecdsa::Pair, payload: Vec<u8>) -> EcdsaSignature {
+	let mut message = format!("\x19Ethereum Signed Message:\n{}", payload.len()).into_bytes();
+	message.extend(payload);
+	let mut signature = [0u8; 65];
+	signature
+		.copy_from_slice(pair.sign_prehashed(&sp_io::hashing::keccak_256(&message)).as_ref());
+	EcdsaSignature(signature)
+}
+
+pub fn new_test_ext(balances: Vec<(AccountId, Balance)>) -> sp_io::TestExternalities {
+	let mut storage = frame_system::GenesisConfig::default().build_storage::<Test>().unwrap();
+	pallet_balances::GenesisConfig::<Test> { balances }
+		.assimilate_storage(&mut storage)
+		.unwrap();
+	let mut ext = sp_io::TestExternalities::from(storage);
+	ext.execute_with(|| System::set_block_number(1));
+	ext
+}
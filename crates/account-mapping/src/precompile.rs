@@ -0,0 +1,120 @@
+//! EVM side of the transfers between native accounts and fallback accounts of EVM addresses.
+//!
+//! The precompile follows the Solidity ABI of
+//!
+//! ```solidity
+//! interface AccountMapping {
+//!     // Move `amount` from the fallback account of the caller to `account`.
+//!     function transferToNative(bytes32 account, uint256 amount) external;
+//!     // Move `amount` from `account` to the fallback account of the caller. `r` and `s` are
+//!     // the sr25519 signature of `account` over `Pallet::transfer_payload`.
+//!     function transferFromNative(bytes32 account, uint256 amount, bytes32 r, bytes32 s) external;
+//! }
+//! ```
+
+use pallet_evm::{
+	ExitRevert, ExitSucceed, Precompile, PrecompileFailure, PrecompileHandle, PrecompileOutput,
+	PrecompileResult,
+};
+use sp_core::{sr25519, U256};
+use sp_runtime::DispatchError;
+use sp_std::{marker::PhantomData, prelude::*};
+
+use crate::{Config, Pallet, TransferDirection, TransferNonces};
+
+/// `bytes4(keccak256("transferToNative(bytes32,uint256)"))`
+pub const TRANSFER_TO_NATIVE_SELECTOR: [u8; 4] = [0x13, 0x96, 0xf8, 0x57];
+/// `bytes4(keccak256("transferFromNative(bytes32,uint256,bytes32,bytes32)"))`
+pub const TRANSFER_FROM_NATIVE_SELECTOR: [u8; 4] = [0xc5, 0x9e, 0xa8, 0x66];
+
+/// Gas charged for a transfer to a native account.
+const TRANSFER_TO_NATIVE_GAS: u64 = 30_000;
+/// Gas charged for a transfer from a native account, which also verifies a signature.
+const TRANSFER_FROM_NATIVE_GAS: u64 = 60_000;
+
+pub struct AccountMappingPrecompile<T>(PhantomData<T>);
+
+impl<T> Precompile for AccountMappingPrecompile<T>
+where
+	T: Config,
+	T::AccountId: From<[u8; 32]>,
+{
+	fn execute(handle: &mut impl PrecompileHandle) -> PrecompileResult {
+		// Under DELEGATECALL the context is the one of the calling contract, which could then
+		// move the funds of whoever called it.
+		if handle.code_address() != handle.context().address {
+			return Err(revert("cannot be called with DELEGATECALL"))
+		}
+		if handle.is_static() {
+			return Err(revert("cannot transfer in a static call"))
+		}
+
+		let input = handle.input().to_vec();
+		if input.len() < 4 {
+			return Err(revert("invalid selector"))
+		}
+		let (selector, args) = input.split_at(4);
+		let caller = handle.context().caller;
+
+		match selector {
+			s if s == TRANSFER_TO_NATIVE_SELECTOR => {
+				handle.record_cost(TRANSFER_TO_NATIVE_GAS)?;
+				let account = word(args, 0)?;
+				let amount = balance::<T>(word(args, 1)?)?;
+
+				Pallet::<T>::do_transfer_from_evm(caller, &account.into(), amount)
+					.map_err(dispatch_revert)?;
+			},
+			s if s == TRANSFER_FROM_NATIVE_SELECTOR => {
+				handle.record_cost(TRANSFER_FROM_NATIVE_GAS)?;
+				let account = word(args, 0)?;
+				let amount = balance::<T>(word(args, 1)?)?;
+				let mut signature = [0u8; 64];
+				signature[..32].copy_from_slice(&word(args, 2)?);
+				signature[32..].copy_from_slice(&word(args, 3)?);
+
+				let who = T::AccountId::from(account);
+				let payload =
+					Pallet::<T>::transfer_payload(TransferDirection::ToEvm, &who, caller, amount);
+				if !sp_io::crypto::sr25519_verify(
+					&sr25519::Signature::from_raw(signature),
+					&payload,
+					&sr25519::Public::from_raw(account),
+				) {
+					return Err(revert("invalid signature"))
+				}
+				TransferNonces::<T>::mutate(caller, |nonce| *nonce = nonce.saturating_add(1));
+
+				Pallet::<T>::do_transfer_to_evm(&who, caller, amount).map_err(dispatch_revert)?;
+			},
+			_ => return Err(revert("invalid selector")),
+		}
+
+		Ok(PrecompileOutput { exit_status: ExitSucceed::Returned, output: vec![] })
+	}
+}
+
+/// The `index`-th 32-byte argument of `args`.
+fn word(args: &[u8], index: usize) -> Result<[u8; 32], PrecompileFailure> {
+	let mut word = [0u8; 32];
+	word.copy_from_slice(
+		args.get(index * 32..(index + 1) * 32).ok_or_else(|| revert("input too short"))?,
+	);
+	Ok(word)
+}
+
+fn balance<T: Config>(word: [u8; 32]) -> Result<crate::BalanceOf<T>, PrecompileFailure> {
+	let amount = U256::from_big_endian(&word);
+	if amount > U256::from(u128::MAX) {
+		return Err(revert("amount too large"))
+	}
+	amount.low_u128().try_into().map_err(|_| revert("amount too large"))
+}
+
+fn dispatch_revert(error: DispatchError) -> PrecompileFailure {
+	revert(error.into())
+}
+
+fn revert(reason: &'static str) -> PrecompileFailure {
+	PrecompileFailure::Revert { exit_status: ExitRevert::Reverted, output: reason.as_bytes().to_vec() }
+}
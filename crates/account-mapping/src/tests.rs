@@ -0,0 +1,306 @@
+//! Tests for the module.
+
+use super::*;
+use frame_support::{
+	assert_noop, assert_ok,
+	traits::{LockableCurrency, WithdrawReasons},
+};
+use mock::*;
+use sp_core::H256;
+use sp_runtime::traits::BadOrigin;
+
+#[test]
+fn claim_links_both_ways_and_moves_fallback_funds() {
+	let key = evm_key("Alice");
+	let address = evm_address(&key);
+	let fallback = LowU64AddressMapping::into_account_id(address);
+
+	new_test_ext(vec![(1, 100), (fallback, 50)]).execute_with(|| {
+		assert_eq!(LinkedAddressMapping::<Test>::into_account_id(address), fallback);
+
+		assert_ok!(AccountMapping::claim_account(
+			RuntimeOrigin::signed(1),
+			address,
+			claim_signature(&key, 1),
+		));
+
+		assert_eq!(AccountMapping::native_account(address), Some(1));
+		assert_eq!(AccountMapping::evm_address(1), Some(address));
+		assert_eq!(LinkedAddressMapping::<Test>::into_account_id(address), 1);
+		assert_eq!(Balances::free_balance(1), 150);
+		assert_eq!(Balances::free_balance(fallback), 0);
+		System::assert_last_event(Event::AccountLinked { account: 1, evm_address: address }.into());
+	});
+}
+
+#[test]
+fn claim_leaves_locked_funds_with_the_fallback_account() {
+	let key = evm_key("Alice");
+	let address = evm_address(&key);
+	let fallback = LowU64AddressMapping::into_account_id(address);
+
+	new_test_ext(vec![(1, 100), (fallback, 50)]).execute_with(|| {
+		Balances::set_lock(*b"democrac", &fallback, 20, WithdrawReasons::all());
+
+		assert_ok!(AccountMapping::claim_account(
+			RuntimeOrigin::signed(1),
+			address,
+			claim_signature(&key, 1),
+		));
+
+		// only the transferable balance is moved
+		assert_eq!(Balances::free_balance(1), 130);
+		assert_eq!(Balances::free_balance(fallback), 20);
+	});
+}
+
+#[test]
+fn claim_is_refused_while_the_fallback_account_is_staked_or_vesting() {
+	let key = evm_key("Alice");
+	let address = evm_address(&key);
+	let fallback = LowU64AddressMapping::into_account_id(address);
+
+	new_test_ext(vec![(1, 100), (fallback, 50)]).execute_with(|| {
+		StakedOrVestingAccounts::set(vec![fallback]);
+		assert_noop!(
+			AccountMapping::claim_account(
+				RuntimeOrigin::signed(1),
+				address,
+				claim_signature(&key, 1),
+			),
+			Error::<Test>::FallbackStakedOrVesting
+		);
+
+		// once unbonded or fully vested
+		StakedOrVestingAccounts::set(vec![]);
+		assert_ok!(AccountMapping::claim_account(
+			RuntimeOrigin::signed(1),
+			address,
+			claim_signature(&key, 1),
+		));
+		assert_eq!(Balances::free_balance(1), 150);
+	});
+}
+
+#[test]
+fn claim_requires_signature_of_the_evm_key() {
+	let key = evm_key("Alice");
+	let address = evm_address(&key);
+
+	new_test_ext(vec![(1, 100), (2, 100)]).execute_with(|| {
+		// signed by another key
+		assert_noop!(
+			AccountMapping::claim_account(
+				RuntimeOrigin::signed(1),
+				address,
+				claim_signature(&evm_key("Bob"), 1),
+			),
+			Error::<Test>::InvalidSignature
+		);
+		// signed for another account
+		assert_noop!(
+			AccountMapping::claim_account(
+				RuntimeOrigin::signed(2),
+				address,
+				claim_signature(&key, 1),
+			),
+			Error::<Test>::InvalidSignature
+		);
+	});
+}
+
+#[test]
+fn signatures_are_bound_to_the_chain() {
+	let key = evm_key("Alice");
+	let address = evm_address(&key);
+	let fallback = LowU64AddressMapping::into_account_id(address);
+
+	new_test_ext(vec![(1, 100), (fallback, 50)]).execute_with(|| {
+		let claim = claim_signature(&key, 1);
+		let transfer = transfer_signature(&key, 1, 30);
+
+		// the same signatures submitted on another chain
+		let genesis_hash = frame_system::BlockHash::<Test>::get(0);
+		frame_system::BlockHash::<Test>::insert(0, H256::repeat_byte(1));
+		assert_noop!(
+			AccountMapping::transfer_from_evm(RuntimeOrigin::signed(1), address, 30, transfer),
+			Error::<Test>::InvalidSignature
+		);
+		assert_noop!(
+			AccountMapping::claim_account(RuntimeOrigin::signed(1), address, claim),
+			Error::<Test>::InvalidSignature
+		);
+
+		frame_system::BlockHash::<Test>::insert(0, genesis_hash);
+		assert_ok!(AccountMapping::transfer_from_evm(
+			RuntimeOrigin::signed(1),
+			address,
+			30,
+			transfer,
+		));
+		assert_ok!(AccountMapping::claim_account(RuntimeOrigin::signed(1), address, claim));
+	});
+}
+
+#[test]
+fn links_are_one_to_one() {
+	let alice = evm_key("Alice");
+	let bob = evm_key("Bob");
+
+	new_test_ext(vec![(1, 100), (2, 100)]).execute_with(|| {
+		assert_ok!(AccountMapping::claim_account(
+			RuntimeOrigin::signed(1),
+			evm_address(&alice),
+			claim_signature(&alice, 1),
+		));
+
+		assert_noop!(
+			AccountMapping::claim_account(
+				RuntimeOrigin::signed(1),
+				evm_address(&bob),
+				claim_signature(&bob, 1),
+			),
+			Error::<Test>::AccountAlreadyLinked
+		);
+		assert_noop!(
+			AccountMapping::claim_account(
+				RuntimeOrigin::signed(2),
+				evm_address(&alice),
+				claim_signature(&alice, 2),
+			),
+			Error::<Test>::EvmAddressAlreadyLinked
+		);
+	});
+}
+
+#[test]
+fn ensure_address_linked_only_accepts_the_linked_account() {
+	let key = evm_key("Alice");
+	let address = evm_address(&key);
+	let fallback = LowU64AddressMapping::into_account_id(address);
+	type Ensure = EnsureAddressLinked<Test, EnsureLowU64>;
+
+	new_test_ext(vec![(1, 100)]).execute_with(|| {
+		// not linked yet, the fallback check applies
+		assert_eq!(
+			Ensure::ensure_address_origin(&address, RuntimeOrigin::signed(fallback)),
+			Ok(fallback)
+		);
+		assert_eq!(
+			Ensure::ensure_address_origin(&address, RuntimeOrigin::signed(1)),
+			Err(BadOrigin)
+		);
+
+		assert_ok!(AccountMapping::claim_account(
+			RuntimeOrigin::signed(1),
+			address,
+			claim_signature(&key, 1),
+		));
+
+		assert_eq!(Ensure::ensure_address_origin(&address, RuntimeOrigin::signed(1)), Ok(1));
+		assert_eq!(
+			Ensure::ensure_address_origin(&address, RuntimeOrigin::signed(fallback)),
+			Err(BadOrigin)
+		);
+	});
+}
+
+#[test]
+fn transfer_to_evm_credits_the_fallback_account() {
+	let address = evm_address(&evm_key("Alice"));
+	let fallback = LowU64AddressMapping::into_account_id(address);
+
+	new_test_ext(vec![(1, 100)]).execute_with(|| {
+		assert_ok!(AccountMapping::transfer_to_evm(RuntimeOrigin::signed(1), address, 40));
+
+		assert_eq!(Balances::free_balance(1), 60);
+		assert_eq!(Balances::free_balance(fallback), 40);
+		System::assert_last_event(
+			Event::TransferredToEvm { account: 1, evm_address: address, amount: 40 }.into(),
+		);
+
+		// more than the free balance
+		assert_noop!(
+			AccountMapping::transfer_to_evm(RuntimeOrigin::signed(1), address, 100),
+			pallet_balances::Error::<Test>::InsufficientBalance
+		);
+	});
+}
+
+#[test]
+fn transfer_from_evm_requires_a_fresh_signature_of_the_evm_key() {
+	let key = evm_key("Alice");
+	let address = evm_address(&key);
+	let fallback = LowU64AddressMapping::into_account_id(address);
+
+	new_test_ext(vec![(1, 100), (fallback, 50)]).execute_with(|| {
+		// signed by another key
+		assert_noop!(
+			AccountMapping::transfer_from_evm(
+				RuntimeOrigin::signed(1),
+				address,
+				30,
+				transfer_signature(&evm_key("Bob"), 1, 30),
+			),
+			Error::<Test>::InvalidSignature
+		);
+		// signed for another amount
+		assert_noop!(
+			AccountMapping::transfer_from_evm(
+				RuntimeOrigin::signed(1),
+				address,
+				40,
+				transfer_signature(&key, 1, 30),
+			),
+			Error::<Test>::InvalidSignature
+		);
+
+		let signature = transfer_signature(&key, 1, 30);
+		assert_ok!(AccountMapping::transfer_from_evm(
+			RuntimeOrigin::signed(1),
+			address,
+			30,
+			signature,
+		));
+		assert_eq!(Balances::free_balance(1), 130);
+		assert_eq!(Balances::free_balance(fallback), 20);
+		assert_eq!(AccountMapping::transfer_nonce(address), 1);
+		System::assert_last_event(
+			Event::TransferredFromEvm { account: 1, evm_address: address, amount: 30 }.into(),
+		);
+
+		// the signature cannot be replayed
+		assert_noop!(
+			AccountMapping::transfer_from_evm(RuntimeOrigin::signed(1), address, 30, signature),
+			Error::<Test>::InvalidSignature
+		);
+	});
+}
+
+#[test]
+fn transfers_are_rejected_for_linked_addresses() {
+	let key = evm_key("Alice");
+	let address = evm_address(&key);
+
+	new_test_ext(vec![(1, 100), (2, 100)]).execute_with(|| {
+		assert_ok!(AccountMapping::claim_account(
+			RuntimeOrigin::signed(1),
+			address,
+			claim_signature(&key, 1),
+		));
+
+		assert_noop!(
+			AccountMapping::transfer_to_evm(RuntimeOrigin::signed(2), address, 10),
+			Error::<Test>::EvmAddressLinked
+		);
+		assert_noop!(
+			AccountMapping::transfer_from_evm(
+				RuntimeOrigin::signed(1),
+				address,
+				10,
+				transfer_signature(&key, 1, 10),
+			),
+			Error::<Test>::EvmAddressLinked
+		);
+	});
+}
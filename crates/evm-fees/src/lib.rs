@@ -0,0 +1,156 @@
+//! # EVM Fees Pallet
+//!
+//! Reports where the fee of every EVM transaction goes.
+//!
+//! The EVM fee adapter of the runtime calls into this pallet when it withdraws a fee from the
+//! payer, and staking does whenever it pays a share of the fees out to a block author or one of
+//! its nominators. Each of these emits a dedicated event of this pallet, so explorers and
+//! accounting tools can reconcile fee flows from Substrate events alone.
+//!
+//! Fees are not shared right away: staking credits them to the block author and holds them
+//! until the slashes of their era are applied, then splits them and pays them out with
+//! `payout_fee_rewards`, which emits its own `Rewarded` events as well.
+//!
+//! Every event is mirrored by a synthetic `pallet_evm::Event::Log`, emitted from
+//! [`Config::LogAddress`], which EVM indexers consuming `EVM.Log` events pick up like any
+//! contract log. The log layouts are those of the following Solidity events:
+//!
+//! ```solidity
+//! event FeeCharged(address indexed payer, uint256 amount);
+//! event ValidatorFeeReward(bytes32 indexed validator, uint256 amount);
+//! event NominatorFeeReward(bytes32 indexed nominator, bytes32 indexed validator, uint256 amount);
+//! ```
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+#[cfg(test)]
+mod mock;
+#[cfg(test)]
+mod tests;
+
+pub use pallet::*;
+
+#[frame_support::pallet]
+pub mod pallet {
+	use codec::Encode;
+	use frame_support::{pallet_prelude::*, traits::Currency};
+	use sp_core::{H160, H256, U256};
+	use sp_runtime::traits::UniqueSaturatedInto;
+	use sp_std::{vec, vec::Vec};
+
+	pub type BalanceOf<T> = <<T as pallet_evm::Config>::Currency as Currency<
+		<T as frame_system::Config>::AccountId,
+	>>::Balance;
+
+	/// `keccak256("FeeCharged(address,uint256)")`
+	pub const FEE_CHARGED_SELECTOR: [u8; 32] =
+		hex_literal::hex!("55bb3cade9d43b798a4fe5ffdd05024b2d7870df53920673bfc7e68047cd0ab1");
+	/// `keccak256("ValidatorFeeReward(bytes32,uint256)")`
+	pub const VALIDATOR_FEE_REWARD_SELECTOR: [u8; 32] =
+		hex_literal::hex!("6267663f50cd550341fbd357b4a6c3087841b17a2191df4c158ab782ad367c3f");
+	/// `keccak256("NominatorFeeReward(bytes32,bytes32,uint256)")`
+	pub const NOMINATOR_FEE_REWARD_SELECTOR: [u8; 32] =
+		hex_literal::hex!("cf7b103824ffd3a090444040553b066cb28f42f6cc1511b9eb4c25129b9bf7da");
+
+	#[pallet::pallet]
+	pub struct Pallet<T>(_);
+
+	#[pallet::config]
+	pub trait Config: frame_system::Config + pallet_evm::Config {
+		/// The overarching event type.
+		type RuntimeEvent: From<Event<Self>>
+			+ IsType<<Self as frame_system::Config>::RuntimeEvent>;
+
+		/// Address the synthetic EVM logs are emitted from.
+		#[pallet::constant]
+		type LogAddress: Get<H160>;
+	}
+
+	#[pallet::event]
+	#[pallet::generate_deposit(pub(super) fn deposit_event)]
+	pub enum Event<T: Config> {
+		/// The fee of an EVM transaction sent by `evm_address` was withdrawn from `payer`.
+		FeeCharged { payer: T::AccountId, evm_address: H160, amount: BalanceOf<T> },
+		/// The share of EVM transaction fees of a block author was paid out to it.
+		ValidatorFeeReward { validator: T::AccountId, amount: BalanceOf<T> },
+		/// The share of EVM transaction fees of a nominator of a block author was paid out to
+		/// it.
+		NominatorFeeReward {
+			nominator: T::AccountId,
+			validator: T::AccountId,
+			amount: BalanceOf<T>,
+		},
+	}
+
+	impl<T: Config> Pallet<T> {
+		/// Report that `amount` was withdrawn from `payer` for a transaction sent by
+		/// `evm_address`.
+		pub fn fee_charged(payer: &T::AccountId, evm_address: H160, amount: BalanceOf<T>) {
+			Self::deposit_log(
+				vec![FEE_CHARGED_SELECTOR.into(), H256::from(evm_address)],
+				amount,
+			);
+			Self::deposit_event(Event::<T>::FeeCharged {
+				payer: payer.clone(),
+				evm_address,
+				amount,
+			});
+		}
+
+		/// Report that `amount` of fees was paid out to the block author `validator`.
+		pub fn validator_fee_reward(validator: &T::AccountId, amount: BalanceOf<T>) {
+			Self::deposit_log(
+				vec![VALIDATOR_FEE_REWARD_SELECTOR.into(), Self::account_topic(validator)],
+				amount,
+			);
+			Self::deposit_event(Event::<T>::ValidatorFeeReward {
+				validator: validator.clone(),
+				amount,
+			});
+		}
+
+		/// Report that `amount` of fees was paid out to `nominator` of the block author
+		/// `validator`.
+		pub fn nominator_fee_reward(
+			nominator: &T::AccountId,
+			validator: &T::AccountId,
+			amount: BalanceOf<T>,
+		) {
+			Self::deposit_log(
+				vec![
+					NOMINATOR_FEE_REWARD_SELECTOR.into(),
+					Self::account_topic(nominator),
+					Self::account_topic(validator),
+				],
+				amount,
+			);
+			Self::deposit_event(Event::<T>::NominatorFeeReward {
+				nominator: nominator.clone(),
+				validator: validator.clone(),
+				amount,
+			});
+		}
+
+		/// 32-byte accounts are used as is, anything else is hashed.
+		fn account_topic(who: &T::AccountId) -> H256 {
+			let encoded = who.encode();
+			if encoded.len() == 32 {
+				H256::from_slice(&encoded)
+			} else {
+				H256::from(sp_io::hashing::keccak_256(&encoded))
+			}
+		}
+
+		fn deposit_log(topics: Vec<H256>, amount: BalanceOf<T>) {
+			let amount: u128 = amount.unique_saturated_into();
+			let mut data = [0u8; 32];
+			U256::from(amount).to_big_endian(&mut data);
+
+			let log = pallet_evm::Log { address: T::LogAddress::get(), topics, data: data.to_vec() };
+			let event = <T as pallet_evm::Config>::RuntimeEvent::from(pallet_evm::Event::<T>::Log {
+				log,
+			});
+			frame_system::Pallet::<T>::deposit_event(event.into());
+		}
+	}
+}
@@ -0,0 +1,125 @@
+//! Tests for the module.
+
+use super::*;
+use frame_support::traits::Get;
+use mock::*;
+use sp_core::{H160, H256, U256};
+
+fn amount_data(amount: u128) -> Vec<u8> {
+	let mut data = [0u8; 32];
+	U256::from(amount).to_big_endian(&mut data);
+	data.to_vec()
+}
+
+#[test]
+fn selectors_are_the_topics_of_the_solidity_events() {
+	let topic = |signature: &[u8]| sp_io::hashing::keccak_256(signature);
+	assert_eq!(FEE_CHARGED_SELECTOR, topic(b"FeeCharged(address,uint256)"));
+	assert_eq!(VALIDATOR_FEE_REWARD_SELECTOR, topic(b"ValidatorFeeReward(bytes32,uint256)"));
+	assert_eq!(
+		NOMINATOR_FEE_REWARD_SELECTOR,
+		topic(b"NominatorFeeReward(bytes32,bytes32,uint256)")
+	);
+}
+
+#[test]
+fn fee_charged_emits_event_and_log() {
+	new_test_ext().execute_with(|| {
+		let evm_address = H160::repeat_byte(0xaa);
+		EvmFees::fee_charged(&account(1), evm_address, 1_000);
+
+		System::assert_last_event(
+			Event::FeeCharged { payer: account(1), evm_address, amount: 1_000 }.into(),
+		);
+		assert_eq!(
+			evm_logs(),
+			vec![pallet_evm::Log {
+				address: LogAddress::get(),
+				topics: vec![FEE_CHARGED_SELECTOR.into(), H256::from(evm_address)],
+				data: amount_data(1_000),
+			}]
+		);
+	});
+}
+
+#[test]
+fn validator_fee_reward_emits_event_and_log() {
+	new_test_ext().execute_with(|| {
+		EvmFees::validator_fee_reward(&account(11), 700);
+
+		System::assert_last_event(
+			Event::ValidatorFeeReward { validator: account(11), amount: 700 }.into(),
+		);
+		assert_eq!(
+			evm_logs(),
+			vec![pallet_evm::Log {
+				address: LogAddress::get(),
+				topics: vec![VALIDATOR_FEE_REWARD_SELECTOR.into(), H256::repeat_byte(11)],
+				data: amount_data(700),
+			}]
+		);
+	});
+}
+
+#[test]
+fn nominator_fee_reward_emits_event_and_log() {
+	new_test_ext().execute_with(|| {
+		EvmFees::nominator_fee_reward(&account(101), &account(11), 300);
+
+		System::assert_last_event(
+			Event::NominatorFeeReward {
+				nominator: account(101),
+				validator: account(11),
+				amount: 300,
+			}
+			.into(),
+		);
+		assert_eq!(
+			evm_logs(),
+			vec![pallet_evm::Log {
+				address: LogAddress::get(),
+				topics: vec![
+					NOMINATOR_FEE_REWARD_SELECTOR.into(),
+					H256::repeat_byte(101),
+					H256::repeat_byte(11),
+				],
+				data: amount_data(300),
+			}]
+		);
+	});
+}
+
+#[test]
+fn fee_split_is_reported_in_order() {
+	new_test_ext().execute_with(|| {
+		let evm_address = H160::repeat_byte(0xaa);
+		EvmFees::fee_charged(&account(1), evm_address, 1_000);
+		EvmFees::validator_fee_reward(&account(11), 700);
+		EvmFees::nominator_fee_reward(&account(101), &account(11), 300);
+
+		let fee_events = System::events()
+			.into_iter()
+			.filter_map(|record| match record.event {
+				RuntimeEvent::EvmFees(event) => Some(event),
+				_ => None,
+			})
+			.collect::<Vec<_>>();
+		assert_eq!(
+			fee_events,
+			vec![
+				Event::FeeCharged { payer: account(1), evm_address, amount: 1_000 },
+				Event::ValidatorFeeReward { validator: account(11), amount: 700 },
+				Event::NominatorFeeReward {
+					nominator: account(101),
+					validator: account(11),
+					amount: 300
+				},
+			]
+		);
+		// every event is mirrored by a log of the same amount
+		assert_eq!(
+			evm_logs().into_iter().map(|log| log.data).collect::<Vec<_>>(),
+			vec![amount_data(1_000), amount_data(700), amount_data(300)]
+		);
+	});
+}
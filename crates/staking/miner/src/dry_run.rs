@@ -0,0 +1,59 @@
+//! Mine a solution at a block and check it, without submitting it.
+
+use pallet_election_provider_multi_phase::{self as epm, ElectionCompute};
+use qchain_template_runtime::Runtime;
+use scale_codec::{Decode, Encode};
+use sp_runtime::{traits::Header as HeaderT, ApplyExtrinsicResult};
+
+use crate::{error::Error, mine, rpc::Rpc, signer::Signer, DryRunConfig, LOG_TARGET};
+
+pub async fn run(
+	rpc: &Rpc,
+	uri: &str,
+	signer: &Signer,
+	config: DryRunConfig,
+) -> Result<(), Error> {
+	let header = rpc.header(config.at).await?;
+	let at = header.hash();
+	let mut ext = mine::election_ext(uri, at).await?;
+
+	let (raw_solution, size) = mine::mine(&mut ext, &config.solver)?;
+	log::info!(
+		target: LOG_TARGET,
+		"mined a solution of round {} at {:?} with {:?}: score {:?}, snapshot of {} voters and {} \
+		targets",
+		raw_solution.round,
+		at,
+		config.solver.solver,
+		raw_solution.score,
+		size.voters,
+		size.targets,
+	);
+
+	// The checks the pallet runs on the solution if it is elected.
+	let ready = ext.execute_with(|| {
+		epm::Pallet::<Runtime>::feasibility_check(raw_solution.clone(), ElectionCompute::Signed)
+	})?;
+	log::info!(
+		target: LOG_TARGET,
+		"the solution is feasible, electing {} validators",
+		ready.supports.len()
+	);
+	if let Err(e) = mine::ensure_worth_submitting(&mut ext, raw_solution.score, &signer.account) {
+		log::warn!(target: LOG_TARGET, "the solution would not be submitted: {}", e);
+	}
+
+	// The checks of the transaction pool and the dispatch of the submission.
+	let genesis_hash = rpc.block_hash(0).await?.expect("the genesis block exists; qed");
+	let nonce = rpc.account_next_index(&signer.account).await?;
+	let version = rpc.runtime_version(Some(at)).await?;
+	let extrinsic =
+		signer.sign(mine::submit_call(raw_solution), nonce, genesis_hash, &header, &version);
+	let encoded = extrinsic.encode();
+	log::info!(target: LOG_TARGET, "the submission is {} bytes long", encoded.len());
+	let outcome = rpc.dry_run(encoded.into(), at).await?;
+	let outcome = ApplyExtrinsicResult::decode(&mut &outcome.0[..])?;
+	log::info!(target: LOG_TARGET, "dry-run of the submission: {:?}", outcome);
+
+	Ok(())
+}
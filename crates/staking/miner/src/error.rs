@@ -0,0 +1,88 @@
+//! The errors of the miner.
+
+use std::fmt;
+
+use pallet_election_provider_multi_phase::{unsigned::MinerError, FeasibilityError};
+use sp_core::crypto::SecretStringError;
+
+#[derive(Debug)]
+pub enum Error {
+	Io(std::io::Error),
+	Rpc(jsonrpsee::core::Error),
+	Codec(scale_codec::Error),
+	RemoteExternalities(&'static str),
+	Crypto(SecretStringError),
+	Mining(MinerError),
+	Feasibility(FeasibilityError),
+	/// The runtime of the node is not the one the miner is built with.
+	IncompatibleRuntime { node: String, miner: String },
+	/// There is no snapshot to mine against, the election is not ongoing.
+	SnapshotUnavailable,
+	/// A solution of the account is already queued in this round.
+	AlreadySubmitted,
+	/// The queue holds a solution at least as good as the mined one.
+	BetterSolutionExists,
+	/// The submission did not make it into a block, or failed there.
+	Submission(String),
+}
+
+impl fmt::Display for Error {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		match self {
+			Error::Io(e) => write!(f, "io error: {}", e),
+			Error::Rpc(e) => write!(f, "rpc error: {}", e),
+			Error::Codec(e) => write!(f, "failed to decode: {}", e),
+			Error::RemoteExternalities(e) => write!(f, "failed to fetch the state: {}", e),
+			Error::Crypto(e) => write!(f, "invalid secret: {:?}", e),
+			Error::Mining(e) => write!(f, "failed to mine: {:?}", e),
+			Error::Feasibility(e) => write!(f, "unfeasible solution: {:?}", e),
+			Error::IncompatibleRuntime { node, miner } => write!(
+				f,
+				"the node runs {}, while the miner is built for {}; rebuild the miner",
+				node, miner
+			),
+			Error::SnapshotUnavailable => write!(f, "no snapshot, the election is not ongoing"),
+			Error::AlreadySubmitted => write!(f, "a solution is already queued by this account"),
+			Error::BetterSolutionExists => write!(f, "a better solution is already queued"),
+			Error::Submission(e) => write!(f, "submission failed: {}", e),
+		}
+	}
+}
+
+impl std::error::Error for Error {}
+
+impl From<std::io::Error> for Error {
+	fn from(e: std::io::Error) -> Self {
+		Error::Io(e)
+	}
+}
+
+impl From<jsonrpsee::core::Error> for Error {
+	fn from(e: jsonrpsee::core::Error) -> Self {
+		Error::Rpc(e)
+	}
+}
+
+impl From<scale_codec::Error> for Error {
+	fn from(e: scale_codec::Error) -> Self {
+		Error::Codec(e)
+	}
+}
+
+impl From<SecretStringError> for Error {
+	fn from(e: SecretStringError) -> Self {
+		Error::Crypto(e)
+	}
+}
+
+impl From<MinerError> for Error {
+	fn from(e: MinerError) -> Self {
+		Error::Mining(e)
+	}
+}
+
+impl From<FeasibilityError> for Error {
+	fn from(e: FeasibilityError) -> Self {
+		Error::Feasibility(e)
+	}
+}
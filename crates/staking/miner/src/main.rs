@@ -0,0 +1,134 @@
+//! Mines NPoS election solutions and submits them to `ElectionProviderMultiPhase` in the signed
+//! phase.
+//!
+//! ```text
+//! # Submit a solution in every signed phase.
+//! staking-miner --uri ws://127.0.0.1:9944 --seed-or-path //Alice monitor
+//! # Mine a solution at the best block, check it and dry-run its submission.
+//! staking-miner --uri ws://127.0.0.1:9944 --seed-or-path //Alice dry-run --solver phrag-mms
+//! ```
+//!
+//! Solutions are mined against the snapshot of the ongoing round with the code of the runtime
+//! the miner is built with, so the miner refuses to run against a node whose runtime has another
+//! `spec_version`. Unlike the unsigned solutions of the offchain workers, signed solutions lock a
+//! deposit, which is returned with a reward if the solution is elected.
+
+mod dry_run;
+mod error;
+mod mine;
+mod monitor;
+mod rpc;
+mod signer;
+
+use clap::{Args, Parser, Subcommand, ValueEnum};
+use qchain_template_runtime::Hash;
+
+use error::Error;
+use rpc::Rpc;
+use signer::Signer;
+
+pub(crate) const LOG_TARGET: &str = "staking-miner";
+
+#[derive(Debug, Parser)]
+#[command(about = "Mine NPoS election solutions and submit them in the signed phase")]
+struct Cli {
+	/// The websocket endpoint of the node.
+	#[arg(long, env = "URI", default_value = "ws://127.0.0.1:9944")]
+	uri: String,
+
+	/// The secret URI of the submitting account (`//Alice`, a mnemonic, ...) or the path of a
+	/// file containing it.
+	#[arg(long, env = "SEED")]
+	seed_or_path: String,
+
+	#[command(subcommand)]
+	command: Command,
+}
+
+#[derive(Debug, Subcommand)]
+enum Command {
+	/// Follow the chain and submit a solution in every signed phase.
+	Monitor(MonitorConfig),
+	/// Mine a solution at a block, check it and dry-run its submission, without submitting it.
+	DryRun(DryRunConfig),
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, ValueEnum)]
+pub(crate) enum Listen {
+	/// Mine on every new best block, and consider a solution submitted once it is in a block.
+	Head,
+	/// Mine on every finalized block, and consider a solution submitted once it is finalized.
+	Finalized,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, ValueEnum)]
+pub(crate) enum Solver {
+	SeqPhragmen,
+	PhragMms,
+}
+
+#[derive(Clone, Debug, Args)]
+pub(crate) struct SolverConfig {
+	/// The NPoS solver to mine with.
+	#[arg(long, value_enum, default_value_t = Solver::SeqPhragmen)]
+	pub solver: Solver,
+
+	/// The number of balancing iterations run on the solution.
+	#[arg(long, default_value_t = 10)]
+	pub iterations: usize,
+}
+
+#[derive(Clone, Debug, Args)]
+pub(crate) struct MonitorConfig {
+	#[arg(long, value_enum, default_value_t = Listen::Head)]
+	pub listen: Listen,
+
+	#[command(flatten)]
+	pub solver: SolverConfig,
+}
+
+#[derive(Clone, Debug, Args)]
+pub(crate) struct DryRunConfig {
+	/// The block to mine at, the best block by default. A snapshot must exist at this block,
+	/// that is the election must be in its signed or unsigned phase.
+	#[arg(long)]
+	pub at: Option<Hash>,
+
+	#[command(flatten)]
+	pub solver: SolverConfig,
+}
+
+/// Check that the runtime of the node is the one the miner is built with.
+async fn ensure_runtime_compatible(rpc: &Rpc) -> Result<(), Error> {
+	let version = rpc.runtime_version(None).await?;
+	let expected = &qchain_template_runtime::VERSION;
+	if version.spec_name != expected.spec_name || version.spec_version != expected.spec_version {
+		return Err(Error::IncompatibleRuntime {
+			node: format!("{}-{}", version.spec_name, version.spec_version),
+			miner: format!("{}-{}", expected.spec_name, expected.spec_version),
+		})
+	}
+	Ok(())
+}
+
+async fn run(cli: Cli) -> Result<(), Error> {
+	let rpc = Rpc::new(&cli.uri).await?;
+	ensure_runtime_compatible(&rpc).await?;
+	let signer = Signer::new(&cli.seed_or_path)?;
+	log::info!(target: LOG_TARGET, "submitting as {}", signer.account);
+
+	match cli.command {
+		Command::Monitor(config) => monitor::run(&rpc, &cli.uri, &signer, config).await,
+		Command::DryRun(config) => dry_run::run(&rpc, &cli.uri, &signer, config).await,
+	}
+}
+
+#[tokio::main]
+async fn main() {
+	env_logger::Builder::from_env(env_logger::Env::default().default_filter_or("info")).init();
+
+	if let Err(e) = run(Cli::parse()).await {
+		log::error!(target: LOG_TARGET, "{}", e);
+		std::process::exit(1);
+	}
+}
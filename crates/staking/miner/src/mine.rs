@@ -0,0 +1,90 @@
+//! Mining of solutions against the state of the chain, with the code of the runtime.
+
+use frame_election_provider_support::{PhragMMS, SequentialPhragmen};
+use frame_support::traits::PalletInfo;
+use pallet_election_provider_multi_phase::{
+	self as epm, unsigned::Miner, RawSolution, RoundSnapshot, SolutionAccuracyOf,
+	SolutionOrSnapshotSize,
+};
+use qchain_template_runtime::{AccountId, Block, Hash, NposSolution16, Runtime, RuntimeCall};
+use remote_externalities::{Builder, Mode, OnlineConfig};
+use sp_npos_elections::{BalancingConfig, ElectionScore};
+use sp_runtime::Perbill;
+
+use crate::{error::Error, Solver, SolverConfig};
+
+frame_support::parameter_types! {
+	/// The balancing of the solvers, set from the command line before mining.
+	pub static Balancing: Option<BalancingConfig> =
+		Some(BalancingConfig { iterations: 10, tolerance: 0 });
+}
+
+/// The state of `ElectionProviderMultiPhase` at block `at`.
+pub async fn election_ext(uri: &str, at: Hash) -> Result<sp_io::TestExternalities, Error> {
+	let pallet = <Runtime as frame_system::Config>::PalletInfo::name::<epm::Pallet<Runtime>>()
+		.expect("the pallet is in the runtime; qed");
+	Builder::<Block>::new()
+		.mode(Mode::Online(OnlineConfig {
+			transport: uri.to_string().into(),
+			at: Some(at),
+			pallets: vec![pallet.to_string()],
+			..Default::default()
+		}))
+		.build()
+		.await
+		.map_err(Error::RemoteExternalities)
+}
+
+/// Mine a solution against the snapshot of the ongoing round, trimmed to fit in a block like
+/// the solutions of the offchain workers.
+pub fn mine(
+	ext: &mut sp_io::TestExternalities,
+	config: &SolverConfig,
+) -> Result<(RawSolution<NposSolution16>, SolutionOrSnapshotSize), Error> {
+	ext.execute_with(|| {
+		let RoundSnapshot { voters, targets } =
+			epm::Snapshot::<Runtime>::get().ok_or(Error::SnapshotUnavailable)?;
+		let desired_targets =
+			epm::DesiredTargets::<Runtime>::get().ok_or(Error::SnapshotUnavailable)?;
+
+		// `Balancing` is thread local, it is set where the solver runs.
+		Balancing::set(Some(BalancingConfig { iterations: config.iterations, tolerance: 0 }));
+		let (solution, score, size) = match config.solver {
+			Solver::SeqPhragmen => Miner::<Runtime>::mine_solution_with_snapshot::<
+				SequentialPhragmen<AccountId, SolutionAccuracyOf<Runtime>, Balancing>,
+			>(voters, targets, desired_targets),
+			Solver::PhragMms => Miner::<Runtime>::mine_solution_with_snapshot::<
+				PhragMMS<AccountId, SolutionAccuracyOf<Runtime>, Balancing>,
+			>(voters, targets, desired_targets),
+		}?;
+
+		Ok((RawSolution { solution, score, round: epm::Round::<Runtime>::get() }, size))
+	})
+}
+
+/// Ensure that a solution of `score` by `who` is worth submitting: `who` has no solution in the
+/// queue yet and the queue holds no solution at least as good.
+pub fn ensure_worth_submitting(
+	ext: &mut sp_io::TestExternalities,
+	score: ElectionScore,
+	who: &AccountId,
+) -> Result<(), Error> {
+	ext.execute_with(|| {
+		if epm::SignedSubmissionsMap::<Runtime>::iter_values().any(|s| s.who == *who) {
+			return Err(Error::AlreadySubmitted)
+		}
+		if epm::SignedSubmissionIndices::<Runtime>::get()
+			.iter()
+			.any(|(other, ..)| !score.strict_threshold_better(*other, Perbill::zero()))
+		{
+			return Err(Error::BetterSolutionExists)
+		}
+		Ok(())
+	})
+}
+
+pub fn submit_call(raw_solution: RawSolution<NposSolution16>) -> RuntimeCall {
+	RuntimeCall::ElectionProviderMultiPhase(epm::Call::submit {
+		raw_solution: Box::new(raw_solution),
+	})
+}
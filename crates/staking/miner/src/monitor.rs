@@ -0,0 +1,116 @@
+//! Follow the chain and submit a solution in every signed phase.
+
+use futures::StreamExt;
+use pallet_election_provider_multi_phase::{self as epm, Phase};
+use qchain_template_runtime::{Hash, Header, Runtime, RuntimeEvent};
+use sc_transaction_pool_api::TransactionStatus;
+use scale_codec::Encode;
+use sp_core::twox_128;
+use sp_runtime::traits::Header as HeaderT;
+
+use crate::{error::Error, mine, rpc::Rpc, signer::Signer, Listen, MonitorConfig, LOG_TARGET};
+
+type BlockNumber = <Runtime as frame_system::Config>::BlockNumber;
+type EventRecord = frame_system::EventRecord<RuntimeEvent, Hash>;
+
+pub async fn run(
+	rpc: &Rpc,
+	uri: &str,
+	signer: &Signer,
+	config: MonitorConfig,
+) -> Result<(), Error> {
+	let genesis_hash = rpc.block_hash(0).await?.expect("the genesis block exists; qed");
+	let mut heads = rpc.subscribe_heads(config.listen).await?;
+	// The last round a solution was stored in.
+	let mut submitted_round = None;
+
+	while let Some(header) = heads.next().await {
+		let header = header?;
+		let at = header.hash();
+
+		let phase = rpc
+			.storage::<Phase<BlockNumber>>(&epm::CurrentPhase::<Runtime>::hashed_key(), at)
+			.await?
+			.unwrap_or_default();
+		if !phase.is_signed() {
+			continue
+		}
+		let round =
+			rpc.storage::<u32>(&epm::Round::<Runtime>::hashed_key(), at).await?.unwrap_or(1);
+		if submitted_round == Some(round) {
+			continue
+		}
+
+		match mine_and_submit(rpc, uri, signer, &config, genesis_hash, &header).await {
+			Ok(block) => {
+				log::info!(
+					target: LOG_TARGET,
+					"solution of round {} stored in block {:?}",
+					round,
+					block
+				);
+				submitted_round = Some(round);
+			},
+			Err(Error::AlreadySubmitted) => submitted_round = Some(round),
+			Err(e) => log::warn!(target: LOG_TARGET, "no solution submitted at {:?}: {}", at, e),
+		}
+	}
+
+	Ok(())
+}
+
+/// Mine a solution at `header` and submit it, returning the block it is stored in.
+async fn mine_and_submit(
+	rpc: &Rpc,
+	uri: &str,
+	signer: &Signer,
+	config: &MonitorConfig,
+	genesis_hash: Hash,
+	header: &Header,
+) -> Result<Hash, Error> {
+	let mut ext = mine::election_ext(uri, header.hash()).await?;
+	let (raw_solution, _) = mine::mine(&mut ext, &config.solver)?;
+	mine::ensure_worth_submitting(&mut ext, raw_solution.score, &signer.account)?;
+	log::info!(
+		target: LOG_TARGET,
+		"submitting a solution of round {} with score {:?}",
+		raw_solution.round,
+		raw_solution.score,
+	);
+
+	let nonce = rpc.account_next_index(&signer.account).await?;
+	let version = rpc.runtime_version(Some(header.hash())).await?;
+	let extrinsic =
+		signer.sign(mine::submit_call(raw_solution), nonce, genesis_hash, header, &version);
+	let mut status = rpc.submit_and_watch(extrinsic.encode().into()).await?;
+
+	while let Some(update) = status.next().await {
+		let block = match update? {
+			TransactionStatus::InBlock(block) if config.listen == Listen::Head => block,
+			TransactionStatus::Finalized(block) => block,
+			TransactionStatus::Future |
+			TransactionStatus::Ready |
+			TransactionStatus::Broadcast(_) |
+			TransactionStatus::InBlock(_) |
+			TransactionStatus::Retracted(_) => continue,
+			other => return Err(Error::Submission(format!("{:?}", other))),
+		};
+
+		// `System::Events` is private to `frame_system`.
+		let events_key = [twox_128(b"System"), twox_128(b"Events")].concat();
+		let events = rpc.storage::<Vec<EventRecord>>(&events_key, block).await?.unwrap_or_default();
+		let stored = events.iter().any(|record| {
+			matches!(
+				record.event,
+				RuntimeEvent::ElectionProviderMultiPhase(epm::Event::SolutionStored { .. })
+			)
+		});
+		return if stored {
+			Ok(block)
+		} else {
+			Err(Error::Submission(format!("the solution was not stored in block {:?}", block)))
+		}
+	}
+
+	Err(Error::Submission("the node stopped reporting the status of the submission".into()))
+}
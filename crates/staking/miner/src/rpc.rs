@@ -0,0 +1,88 @@
+//! The RPC methods of the node used by the miner.
+
+use jsonrpsee::{
+	core::client::{ClientT, Subscription, SubscriptionClientT},
+	rpc_params,
+	ws_client::{WsClient, WsClientBuilder},
+};
+use qchain_template_runtime::{AccountId, Hash, Header, Index};
+use sc_transaction_pool_api::TransactionStatus;
+use scale_codec::Decode;
+use sp_core::{
+	storage::{StorageData, StorageKey},
+	Bytes,
+};
+use sp_version::RuntimeVersion;
+
+use crate::{error::Error, Listen};
+
+pub struct Rpc {
+	client: WsClient,
+}
+
+impl Rpc {
+	pub async fn new(uri: &str) -> Result<Self, Error> {
+		// The snapshot of a round is larger than the default limits.
+		let client = WsClientBuilder::default()
+			.max_request_body_size(u32::MAX)
+			.build(uri)
+			.await?;
+		Ok(Self { client })
+	}
+
+	/// The decoded value at `key` in the state of block `at`.
+	pub async fn storage<T: Decode>(&self, key: &[u8], at: Hash) -> Result<Option<T>, Error> {
+		let data: Option<StorageData> = self
+			.client
+			.request("state_getStorage", rpc_params![StorageKey(key.to_vec()), at])
+			.await?;
+		Ok(data.map(|data| T::decode(&mut &data.0[..])).transpose()?)
+	}
+
+	pub async fn block_hash(&self, number: u32) -> Result<Option<Hash>, Error> {
+		Ok(self.client.request("chain_getBlockHash", rpc_params![number]).await?)
+	}
+
+	/// The header of block `at`, of the best block if `None`.
+	pub async fn header(&self, at: Option<Hash>) -> Result<Header, Error> {
+		let header: Option<Header> = self.client.request("chain_getHeader", rpc_params![at]).await?;
+		header.ok_or_else(|| Error::Submission(format!("unknown block {:?}", at)))
+	}
+
+	pub async fn runtime_version(&self, at: Option<Hash>) -> Result<RuntimeVersion, Error> {
+		Ok(self.client.request("state_getRuntimeVersion", rpc_params![at]).await?)
+	}
+
+	/// The next nonce of `who`, accounting for its transactions in the pool.
+	pub async fn account_next_index(&self, who: &AccountId) -> Result<Index, Error> {
+		Ok(self.client.request("system_accountNextIndex", rpc_params![who]).await?)
+	}
+
+	/// The encoded `ApplyExtrinsicResult` of `extrinsic` applied on top of block `at`.
+	pub async fn dry_run(&self, extrinsic: Bytes, at: Hash) -> Result<Bytes, Error> {
+		Ok(self.client.request("system_dryRun", rpc_params![extrinsic, at]).await?)
+	}
+
+	pub async fn subscribe_heads(&self, listen: Listen) -> Result<Subscription<Header>, Error> {
+		let (subscribe, unsubscribe) = match listen {
+			Listen::Head => ("chain_subscribeNewHeads", "chain_unsubscribeNewHeads"),
+			Listen::Finalized =>
+				("chain_subscribeFinalizedHeads", "chain_unsubscribeFinalizedHeads"),
+		};
+		Ok(self.client.subscribe(subscribe, rpc_params![], unsubscribe).await?)
+	}
+
+	pub async fn submit_and_watch(
+		&self,
+		extrinsic: Bytes,
+	) -> Result<Subscription<TransactionStatus<Hash, Hash>>, Error> {
+		Ok(self
+			.client
+			.subscribe(
+				"author_submitAndWatchExtrinsic",
+				rpc_params![extrinsic],
+				"author_unwatchExtrinsic",
+			)
+			.await?)
+	}
+}
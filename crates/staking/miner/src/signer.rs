@@ -0,0 +1,103 @@
+//! The account submitting solutions.
+
+use std::path::Path;
+
+use qchain_template_runtime::{
+	AccountId, BlockHashCount, Header, Index, Runtime, RuntimeCall, Signature, SignedExtra,
+	SignedPayload, UncheckedExtrinsic,
+};
+use scale_codec::Encode;
+use sp_core::{sr25519, Pair, H256};
+use sp_runtime::{generic::Era, traits::Header as HeaderT, SaturatedConversion};
+use sp_version::RuntimeVersion;
+
+use crate::error::Error;
+
+pub struct Signer {
+	pair: sr25519::Pair,
+	pub account: AccountId,
+}
+
+impl Signer {
+	/// The signer of the secret URI `seed_or_path`, or of the secret URI in the file at this
+	/// path.
+	pub fn new(seed_or_path: &str) -> Result<Self, Error> {
+		let seed = if Path::new(seed_or_path).is_file() {
+			std::fs::read_to_string(seed_or_path)?
+		} else {
+			seed_or_path.to_string()
+		};
+		let pair = sr25519::Pair::from_string(seed.trim(), None)?;
+		let account = AccountId::from(pair.public());
+		Ok(Self { pair, account })
+	}
+
+	/// Sign `call` with `nonce`, for the chain of `genesis_hash`. The transaction is mortal,
+	/// from block `best`, and checked against `version`, the runtime version at this block.
+	pub fn sign(
+		&self,
+		call: RuntimeCall,
+		nonce: Index,
+		genesis_hash: H256,
+		best: &Header,
+		version: &RuntimeVersion,
+	) -> UncheckedExtrinsic {
+		let period =
+			BlockHashCount::get().checked_next_power_of_two().map(|c| c / 2).unwrap_or(2) as u64;
+		let extra: SignedExtra = (
+			frame_system::CheckNonZeroSender::<Runtime>::new(),
+			frame_system::CheckSpecVersion::<Runtime>::new(),
+			frame_system::CheckTxVersion::<Runtime>::new(),
+			frame_system::CheckGenesis::<Runtime>::new(),
+			frame_system::CheckMortality::<Runtime>::from(Era::mortal(
+				period,
+				(*best.number()).saturated_into(),
+			)),
+			frame_system::CheckNonce::<Runtime>::from(nonce),
+			frame_system::CheckWeight::<Runtime>::new(),
+			pallet_transaction_payment::ChargeTransactionPayment::<Runtime>::from(0),
+		);
+
+		let raw_payload = SignedPayload::from_raw(
+			call.clone(),
+			extra.clone(),
+			(
+				(),
+				version.spec_version,
+				version.transaction_version,
+				genesis_hash,
+				best.hash(),
+				(),
+				(),
+				(),
+			),
+		);
+		let signature = raw_payload.using_encoded(|payload| self.pair.sign(payload));
+
+		UncheckedExtrinsic::new_signed(
+			call,
+			self.account.clone().into(),
+			Signature::Sr25519(signature),
+			extra,
+		)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use sp_keyring::AccountKeyring;
+
+	#[test]
+	fn signer_from_seed_or_file() {
+		let alice = AccountId::from(AccountKeyring::Alice.public());
+		assert_eq!(Signer::new("//Alice").unwrap().account, alice);
+
+		let path = std::env::temp_dir().join("staking-miner-seed");
+		std::fs::write(&path, "//Alice\n").unwrap();
+		assert_eq!(Signer::new(path.to_str().unwrap()).unwrap().account, alice);
+		std::fs::remove_file(&path).unwrap();
+
+		assert!(Signer::new("not a secret").is_err());
+	}
+}
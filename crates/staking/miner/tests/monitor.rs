@@ -0,0 +1,85 @@
+//! Runs the miner against a local development node.
+//!
+//! The node is not built by this crate: build it with `cargo build --release -p
+//! qchain-template-node` or point `QCHAIN_NODE` at a binary, then run
+//! `cargo test -p staking-miner -- --ignored`.
+
+use std::{
+	io::{BufRead, BufReader},
+	net::TcpStream,
+	path::PathBuf,
+	process::{Child, Command, Stdio},
+	sync::mpsc,
+	thread,
+	time::{Duration, Instant},
+};
+
+const WS_PORT: u16 = 19944;
+/// The first election of the development chain ends with its first era, after 6 sessions of
+/// 20 blocks.
+const TIMEOUT: Duration = Duration::from_secs(15 * 60);
+
+/// Kills the process when dropped, including when the test panics.
+struct KillOnDrop(Child);
+
+impl Drop for KillOnDrop {
+	fn drop(&mut self) {
+		let _ = self.0.kill();
+	}
+}
+
+fn node_binary() -> PathBuf {
+	std::env::var_os("QCHAIN_NODE").map(PathBuf::from).unwrap_or_else(|| {
+		PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+			.join("../../../target/release/qchain-template-node")
+	})
+}
+
+#[test]
+#[ignore = "needs a built node and runs until the end of the first era"]
+fn submits_a_solution_to_a_dev_node() {
+	let _node = KillOnDrop(
+		Command::new(node_binary())
+			.args(["--dev", "--tmp", "--no-telemetry", "--no-prometheus"])
+			.args(["--ws-port", &WS_PORT.to_string()])
+			.stdout(Stdio::null())
+			.stderr(Stdio::null())
+			.spawn()
+			.expect("the node is built, see the docs of this test"),
+	);
+
+	let started = Instant::now();
+	while TcpStream::connect(("127.0.0.1", WS_PORT)).is_err() {
+		assert!(started.elapsed() < Duration::from_secs(60), "the node did not start");
+		thread::sleep(Duration::from_secs(1));
+	}
+
+	let mut miner = KillOnDrop(
+		Command::new(env!("CARGO_BIN_EXE_staking-miner"))
+			.args(["--uri", &format!("ws://127.0.0.1:{}", WS_PORT), "--seed-or-path", "//Alice"])
+			.arg("monitor")
+			.env("RUST_LOG", "staking-miner=info")
+			.stderr(Stdio::piped())
+			.spawn()
+			.unwrap(),
+	);
+
+	let (sender, logs) = mpsc::channel();
+	let stderr = miner.0.stderr.take().unwrap();
+	thread::spawn(move || {
+		for line in BufReader::new(stderr).lines().flatten() {
+			if sender.send(line).is_err() {
+				break
+			}
+		}
+	});
+
+	loop {
+		let remaining = TIMEOUT.checked_sub(started.elapsed()).expect("no solution was stored");
+		let line = logs.recv_timeout(remaining).expect("no solution was stored");
+		assert!(!line.contains("ERROR"), "the miner failed: {}", line);
+		if line.contains("stored in block") {
+			break
+		}
+	}
+}
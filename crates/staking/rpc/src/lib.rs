@@ -0,0 +1,235 @@
+//! RPC interface for the staking pallet.
+
+use std::{marker::PhantomData, sync::Arc};
+
+use codec::Codec;
+use jsonrpsee::{
+	core::RpcResult,
+	proc_macros::rpc,
+	types::error::{CallError, ErrorObject},
+};
+use serde::{Deserialize, Serialize};
+use sp_api::ProvideRuntimeApi;
+use sp_blockchain::HeaderBackend;
+use sp_rpc::number::NumberOrHex;
+use sp_runtime::traits::Block as BlockT;
+
+pub use pallet_staking_runtime_api::{StakingApi as StakingRuntimeApi, Timing};
+use pallet_staking_runtime_api::{Judgement, UnappliedSlash, ValidatorIdentity};
+
+/// The identity of a validator candidate, as shown to nominators.
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ValidatorInfo<AccountId> {
+	/// The stash of the validator.
+	pub validator: AccountId,
+	/// The display name of the validator, if it set an identity.
+	pub display: Option<String>,
+	/// The judgements of registrars on the identity, by registrar index.
+	pub judgements: Vec<(u32, JudgementInfo)>,
+}
+
+/// Serializable counterpart of [`Judgement`].
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum JudgementInfo {
+	Unknown,
+	FeePaid,
+	Reasonable,
+	KnownGood,
+	OutOfDate,
+	LowQuality,
+	Erroneous,
+}
+
+impl From<Judgement> for JudgementInfo {
+	fn from(judgement: Judgement) -> Self {
+		match judgement {
+			Judgement::Unknown => Self::Unknown,
+			Judgement::FeePaid => Self::FeePaid,
+			Judgement::Reasonable => Self::Reasonable,
+			Judgement::KnownGood => Self::KnownGood,
+			Judgement::OutOfDate => Self::OutOfDate,
+			Judgement::LowQuality => Self::LowQuality,
+			Judgement::Erroneous => Self::Erroneous,
+		}
+	}
+}
+
+impl<AccountId> From<ValidatorIdentity<AccountId>> for ValidatorInfo<AccountId> {
+	fn from(identity: ValidatorIdentity<AccountId>) -> Self {
+		Self {
+			validator: identity.validator,
+			display: identity.display.map(|name| String::from_utf8_lossy(&name).into_owned()),
+			judgements: identity
+				.judgements
+				.into_iter()
+				.map(|(registrar, judgement)| (registrar, judgement.into()))
+				.collect(),
+		}
+	}
+}
+
+/// Serializable counterpart of [`Timing`]. Durations are in milliseconds.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TimingInfo {
+	pub block_time: u64,
+	pub slot_duration: u64,
+	/// The length of an epoch, in slots.
+	pub epoch_duration: u64,
+	pub session_duration: u64,
+	pub sessions_per_era: u32,
+	pub era_duration: u64,
+}
+
+impl From<Timing> for TimingInfo {
+	fn from(timing: Timing) -> Self {
+		Self {
+			block_time: timing.block_time,
+			slot_duration: timing.slot_duration,
+			epoch_duration: timing.epoch_duration,
+			session_duration: timing.session_duration,
+			sessions_per_era: timing.sessions_per_era,
+			era_duration: timing.era_duration,
+		}
+	}
+}
+
+/// Serializable counterpart of [`UnappliedSlash`].
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UnappliedSlashInfo<AccountId> {
+	/// The era the slash is applied at the start of, unless it is cancelled before.
+	pub apply_era: u32,
+	/// The index of the slash in `apply_era`, to cancel it with `cancel_deferred_slash`.
+	pub index: u32,
+	/// The era of the offence.
+	pub slash_era: u32,
+	pub validator: AccountId,
+	pub own: NumberOrHex,
+	pub others: Vec<(AccountId, NumberOrHex)>,
+	pub payout: NumberOrHex,
+	/// The EVM fee rewards credited to the validator in the era of the offence.
+	pub fee_rewards: NumberOrHex,
+}
+
+impl<AccountId, Balance: Into<NumberOrHex>> From<UnappliedSlash<AccountId, Balance>>
+	for UnappliedSlashInfo<AccountId>
+{
+	fn from(slash: UnappliedSlash<AccountId, Balance>) -> Self {
+		Self {
+			apply_era: slash.apply_era,
+			index: slash.index,
+			slash_era: slash.slash_era,
+			validator: slash.validator,
+			own: slash.own.into(),
+			others: slash.others.into_iter().map(|(who, value)| (who, value.into())).collect(),
+			payout: slash.payout.into(),
+			fee_rewards: slash.fee_rewards.into(),
+		}
+	}
+}
+
+#[rpc(client, server)]
+pub trait StakingApi<BlockHash, AccountId> {
+	/// The display names and judgements of every validator candidate.
+	#[method(name = "staking_validatorIdentities")]
+	fn validator_identities(&self, at: Option<BlockHash>) -> RpcResult<Vec<ValidatorInfo<AccountId>>>;
+
+	/// The durations of blocks, epochs, sessions and eras.
+	#[method(name = "staking_timing")]
+	fn timing(&self, at: Option<BlockHash>) -> RpcResult<TimingInfo>;
+
+	/// The slashes waiting to be applied, ordered by the era they are applied at.
+	#[method(name = "staking_unappliedSlashes")]
+	fn unapplied_slashes(
+		&self,
+		at: Option<BlockHash>,
+	) -> RpcResult<Vec<UnappliedSlashInfo<AccountId>>>;
+}
+
+/// Error type of this RPC api.
+pub enum Error {
+	/// The call to runtime failed.
+	RuntimeError,
+}
+
+impl From<Error> for i32 {
+	fn from(e: Error) -> i32 {
+		match e {
+			Error::RuntimeError => 1,
+		}
+	}
+}
+
+/// Provides RPC methods to query staking information.
+pub struct Staking<C, B, Balance> {
+	client: Arc<C>,
+	_marker: PhantomData<(B, Balance)>,
+}
+
+impl<C, B, Balance> Staking<C, B, Balance> {
+	/// Creates a new instance of the Staking Rpc helper.
+	pub fn new(client: Arc<C>) -> Self {
+		Self { client, _marker: Default::default() }
+	}
+}
+
+impl<C, Block, Balance, AccountId> StakingApiServer<<Block as BlockT>::Hash, AccountId>
+	for Staking<C, Block, Balance>
+where
+	Block: BlockT,
+	C: ProvideRuntimeApi<Block> + HeaderBackend<Block> + Send + Sync + 'static,
+	C::Api: StakingRuntimeApi<Block, Balance, AccountId>,
+	Balance: Codec + Into<NumberOrHex> + Send + Sync + 'static,
+	AccountId: Codec + Serialize + Send + Sync + 'static,
+{
+	fn validator_identities(
+		&self,
+		at: Option<Block::Hash>,
+	) -> RpcResult<Vec<ValidatorInfo<AccountId>>> {
+		let api = self.client.runtime_api();
+		let at = at.unwrap_or_else(|| self.client.info().best_hash);
+
+		let identities = api.validator_identities(at).map_err(|e| {
+			CallError::Custom(ErrorObject::owned(
+				Error::RuntimeError.into(),
+				"Unable to query validator identities.",
+				Some(e.to_string()),
+			))
+		})?;
+		Ok(identities.into_iter().map(Into::into).collect())
+	}
+
+	fn timing(&self, at: Option<Block::Hash>) -> RpcResult<TimingInfo> {
+		let api = self.client.runtime_api();
+		let at = at.unwrap_or_else(|| self.client.info().best_hash);
+
+		let timing = api.timing(at).map_err(|e| {
+			CallError::Custom(ErrorObject::owned(
+				Error::RuntimeError.into(),
+				"Unable to query timing.",
+				Some(e.to_string()),
+			))
+		})?;
+		Ok(timing.into())
+	}
+
+	fn unapplied_slashes(
+		&self,
+		at: Option<Block::Hash>,
+	) -> RpcResult<Vec<UnappliedSlashInfo<AccountId>>> {
+		let api = self.client.runtime_api();
+		let at = at.unwrap_or_else(|| self.client.info().best_hash);
+
+		let slashes = api.unapplied_slashes(at).map_err(|e| {
+			CallError::Custom(ErrorObject::owned(
+				Error::RuntimeError.into(),
+				"Unable to query unapplied slashes.",
+				Some(e.to_string()),
+			))
+		})?;
+		Ok(slashes.into_iter().map(Into::into).collect())
+	}
+}
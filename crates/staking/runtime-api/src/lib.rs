@@ -19,14 +19,115 @@
 
 #![cfg_attr(not(feature = "std"), no_std)]
 
-use codec::Codec;
+use codec::{Codec, Decode, Encode};
+use sp_arithmetic::Perbill;
+use sp_staking::EraIndex;
+use sp_std::vec::Vec;
+
+/// The judgement a registrar gave on the identity of a validator.
+#[derive(Clone, Copy, PartialEq, Eq, Encode, Decode)]
+#[cfg_attr(feature = "std", derive(Debug))]
+pub enum Judgement {
+	/// The default value; no opinion is held.
+	Unknown,
+	/// No judgement is yet in place, but a deposit is reserved as payment for providing one.
+	FeePaid,
+	/// The data appears to be reasonably acceptable in terms of its accuracy.
+	Reasonable,
+	/// The target is known directly by the registrar and the registrar can fully attest to
+	/// the data's accuracy.
+	KnownGood,
+	/// The data was once good but is currently out of date.
+	OutOfDate,
+	/// The data is imprecise or of sufficiently low-quality to be problematic.
+	LowQuality,
+	/// The data is erroneous.
+	Erroneous,
+}
+
+/// The on-chain identity of a validator.
+#[derive(Clone, PartialEq, Eq, Encode, Decode)]
+#[cfg_attr(feature = "std", derive(Debug))]
+pub struct ValidatorIdentity<AccountId> {
+	/// The stash of the validator.
+	pub validator: AccountId,
+	/// The display name of the validator. For a sub-account, the display name of its parent
+	/// identity followed by `/` and the name of the sub-account.
+	pub display: Option<Vec<u8>>,
+	/// The judgements of the identity, by registrar index. For a sub-account, the judgements of
+	/// its parent identity.
+	pub judgements: Vec<(u32, Judgement)>,
+}
+
+/// The timing of block production, sessions and eras.
+#[derive(Clone, Copy, PartialEq, Eq, Encode, Decode)]
+#[cfg_attr(feature = "std", derive(Debug))]
+pub struct Timing {
+	/// The expected time between blocks, in milliseconds.
+	pub block_time: u64,
+	/// The duration of a slot, in milliseconds.
+	pub slot_duration: u64,
+	/// The length of an epoch, in slots.
+	pub epoch_duration: u64,
+	/// The expected duration of a session, which lasts an epoch, in milliseconds.
+	pub session_duration: u64,
+	/// The number of sessions in an era.
+	pub sessions_per_era: u32,
+	/// The expected duration of an era, in milliseconds.
+	pub era_duration: u64,
+}
+
+/// A slash computed for an offence and deferred, waiting to be applied.
+#[derive(Clone, PartialEq, Eq, Encode, Decode)]
+#[cfg_attr(feature = "std", derive(Debug))]
+pub struct UnappliedSlash<AccountId, Balance> {
+	/// The era the slash is applied at the start of, unless it is cancelled before.
+	pub apply_era: EraIndex,
+	/// The index of the slash among those applied in `apply_era`, as expected by
+	/// `cancel_deferred_slash`.
+	pub index: u32,
+	/// The era of the offence.
+	pub slash_era: EraIndex,
+	/// The stash of the offending validator.
+	pub validator: AccountId,
+	/// The amount slashed from the own stake of the validator.
+	pub own: Balance,
+	/// The nominators slashed, and the amounts.
+	pub others: Vec<(AccountId, Balance)>,
+	/// The part of the slashed amount paid to the reporters of the offence.
+	pub payout: Balance,
+	/// The EVM fee rewards credited to the validator in the era of the offence.
+	pub fee_rewards: Balance,
+}
 
 sp_api::decl_runtime_apis! {
-	pub trait StakingApi<Balance>
+	/// Version 2 adds every call but `nominations_quota`.
+	#[api_version(2)]
+	pub trait StakingApi<Balance, AccountId>
 		where
 			Balance: Codec,
+			AccountId: Codec,
 	{
 		/// Returns the nominations quota for a nominator with a given balance.
 		fn nominations_quota(balance: Balance) -> u32;
+
+		/// Returns the EVM fee rewards credited to all validators in each era still in history.
+		fn eras_fee_rewards() -> Vec<(EraIndex, Balance)>;
+
+		/// Returns the EVM fee rewards credited to `validator` in each era still in history.
+		fn validator_fee_rewards(validator: AccountId) -> Vec<(EraIndex, Balance)>;
+
+		/// Returns the identity of every validator candidate.
+		fn validator_identities() -> Vec<ValidatorIdentity<AccountId>>;
+
+		/// Returns the payout to stakers and the rest of the maximum payout, for an era of the
+		/// expected duration, if `staking_ratio` of the current issuance was staked.
+		fn era_payout(staking_ratio: Perbill) -> (Balance, Balance);
+
+		/// Returns the durations of blocks, epochs, sessions and eras.
+		fn timing() -> Timing;
+
+		/// Returns the slashes waiting to be applied, ordered by the era they are applied at.
+		fn unapplied_slashes() -> Vec<UnappliedSlash<AccountId, Balance>>;
 	}
 }
@@ -0,0 +1,105 @@
+//! Parsing of inflation curves given in the syntax of `pallet_staking_reward_curve::build!`:
+//!
+//! ```text
+//! min_inflation: 0_025_000,
+//! max_inflation: 0_100_000,
+//! ideal_stake: 0_500_000,
+//! falloff: 0_050_000,
+//! max_piece_count: 40,
+//! test_precision: 0_005_000,
+//! ```
+//!
+//! optionally wrapped in `curve!( .. )`. Values are in millionths. The curve is built like the
+//! runtime builds it from its storage parameters, with
+//! [`MAX_INFLATION_CURVE_POINTS`](pallet_staking::inflation::MAX_INFLATION_CURVE_POINTS) points,
+//! so `max_piece_count` and `test_precision` are accepted but have no effect.
+
+use pallet_staking::InflationParams;
+use sp_runtime::Perbill;
+
+/// Parse `spec` into the parameters of a curve.
+pub fn parse(spec: &str) -> Result<InflationParams, String> {
+	let spec = spec.trim();
+	let spec = spec
+		.strip_prefix("curve!(")
+		.and_then(|inner| inner.strip_suffix(')'))
+		.unwrap_or(spec);
+
+	let (mut min_inflation, mut max_inflation, mut ideal_stake, mut falloff) =
+		(None, None, None, None);
+	for field in spec.split(',').map(str::trim).filter(|field| !field.is_empty()) {
+		let (name, value) =
+			field.split_once(':').ok_or_else(|| format!("expected `name: value`, got `{}`", field))?;
+		let value = value
+			.trim()
+			.replace('_', "")
+			.parse::<u32>()
+			.map_err(|e| format!("invalid value of `{}`: {}", name.trim(), e))?;
+		let slot = match name.trim() {
+			"min_inflation" => &mut min_inflation,
+			"max_inflation" => &mut max_inflation,
+			"ideal_stake" => &mut ideal_stake,
+			"falloff" => &mut falloff,
+			"max_piece_count" | "test_precision" => continue,
+			other => return Err(format!("unknown field `{}`", other)),
+		};
+		if value > 1_000_000 {
+			return Err(format!("`{}` must be at most 1_000_000", name.trim()))
+		}
+		*slot = Some(Perbill::from_parts(value * 1_000));
+	}
+
+	let required = |value: Option<Perbill>, name: &str| {
+		value.ok_or_else(|| format!("missing field `{}`", name))
+	};
+	let params = InflationParams {
+		min_inflation: required(min_inflation, "min_inflation")?,
+		max_inflation: required(max_inflation, "max_inflation")?,
+		ideal_stake: required(ideal_stake, "ideal_stake")?,
+		falloff: required(falloff, "falloff")?,
+	};
+	if !params.is_valid() {
+		return Err("the minimum inflation must not exceed the maximum, the ideal stake must be \
+			strictly between 0 and 1_000_000 and the falloff at least 0_010_000"
+			.into())
+	}
+	Ok(params)
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn parses_reward_curve_syntax() {
+		let expected = InflationParams {
+			min_inflation: Perbill::from_parts(25_000_000),
+			max_inflation: Perbill::from_percent(10),
+			ideal_stake: Perbill::from_percent(50),
+			falloff: Perbill::from_percent(5),
+		};
+		let fields = "min_inflation: 0_025_000,
+			max_inflation: 0_100_000,
+			ideal_stake: 0_500_000,
+			falloff: 0_050_000,
+			max_piece_count: 40,
+			test_precision: 0_005_000,";
+
+		assert_eq!(parse(fields), Ok(expected));
+		assert_eq!(parse(&format!("curve!({})", fields)), Ok(expected));
+	}
+
+	#[test]
+	fn rejects_invalid_curves() {
+		assert!(parse("min_inflation: 25000, max_inflation: 100000, ideal_stake: 500000").is_err());
+		assert!(parse("min_inflation: 25000, max_inflation: 100000, ideal_stake: 500000, \
+			falloff: 50000, slope: 1")
+		.is_err());
+		assert!(parse("min_inflation: 125000, max_inflation: 100000, ideal_stake: 500000, \
+			falloff: 50000")
+		.is_err());
+		assert!(parse("min_inflation: 25000, max_inflation: 100000, ideal_stake: 1500000, \
+			falloff: 50000")
+		.is_err());
+	}
+}
@@ -0,0 +1,163 @@
+//! Projects era payouts, issuance and staking yields with the payout code of the runtime.
+//!
+//! ```text
+//! staking-simulator \
+//!     --staking-ratio 0.5 \
+//!     --total-issuance 1000000000000000000000000000 \
+//!     --era-millis 86400000 \
+//!     --curve "min_inflation: 0_025_000, max_inflation: 0_100_000, ideal_stake: 0_500_000, falloff: 0_050_000" \
+//!     --era-fees 1000000000000000000000 \
+//!     --target-yield 15% \
+//!     --format json
+//! ```
+//!
+//! Without `--target-yield` the payout follows the curve alone, like `InflationCurve`, with it
+//! inflation is offset by fees like `FeeAwareEraPayout` does.
+
+mod curve;
+mod projection;
+
+use clap::{Parser, ValueEnum};
+use sp_runtime::Perbill;
+
+use projection::{Assumptions, Era};
+
+#[derive(Clone, Copy, Debug, ValueEnum)]
+enum Format {
+	Csv,
+	Json,
+}
+
+#[derive(Debug, Parser)]
+#[command(about = "Project era payouts, issuance and staking yields")]
+struct Cli {
+	/// The share of the issuance that is staked, as a fraction (`0.5`) or a percentage (`50%`).
+	#[arg(long, value_parser = parse_perbill)]
+	staking_ratio: Perbill,
+
+	/// The total issuance at the start of the projection.
+	#[arg(long)]
+	total_issuance: u128,
+
+	/// The length of an era in milliseconds.
+	#[arg(long)]
+	era_millis: u64,
+
+	/// The inflation curve, in the syntax of `pallet_staking_reward_curve::build!`.
+	#[arg(long, value_parser = curve::parse)]
+	curve: pallet_staking::InflationParams,
+
+	/// The EVM fees paid to stakers in every era.
+	#[arg(long, default_value_t = 0)]
+	era_fees: u128,
+
+	/// Offset inflation by fees beyond this yearly staking yield.
+	#[arg(long, value_parser = parse_perbill)]
+	target_yield: Option<Perbill>,
+
+	/// The commission of validators.
+	#[arg(long, value_parser = parse_perbill, default_value = "0")]
+	commission: Perbill,
+
+	/// The share of the stake backing validators that is their own.
+	#[arg(long, value_parser = parse_perbill, default_value = "10%")]
+	validator_own_ratio: Perbill,
+
+	/// The number of eras to project, a year by default.
+	#[arg(long)]
+	eras: Option<u32>,
+
+	#[arg(long, value_enum, default_value_t = Format::Csv)]
+	format: Format,
+}
+
+/// Parse a fraction given as a decimal (`0.125`) or a percentage (`12.5%`) into a [`Perbill`],
+/// without going through floating point.
+fn parse_perbill(input: &str) -> Result<Perbill, String> {
+	let input = input.trim();
+	let (number, scale) = match input.strip_suffix('%') {
+		Some(number) => (number.trim(), 100u128),
+		None => (input, 1u128),
+	};
+	let (integer, decimals) = number.split_once('.').unwrap_or((number, ""));
+	if decimals.len() > 9 {
+		return Err(format!("`{}` is more precise than a billionth", input))
+	}
+	let digits = |s: &str| {
+		if s.chars().all(|c| c.is_ascii_digit()) {
+			Ok(s.parse::<u128>().unwrap_or(0))
+		} else {
+			Err(format!("`{}` is not a fraction", input))
+		}
+	};
+	let parts = (digits(integer)? * 1_000_000_000 +
+		digits(decimals)? * 10u128.pow(9 - decimals.len() as u32)) /
+		scale;
+	if parts > 1_000_000_000 {
+		return Err(format!("`{}` is more than one", input))
+	}
+	Ok(Perbill::from_parts(parts as u32))
+}
+
+fn write_csv(eras: &[Era]) {
+	println!(
+		"era,total_issuance,total_staked,staker_payout,fees,treasury_remainder,minted,\
+		annual_inflation,validator_yield,nominator_yield"
+	);
+	for era in eras {
+		println!(
+			"{},{},{},{},{},{},{},{},{},{}",
+			era.era,
+			era.total_issuance,
+			era.total_staked,
+			era.staker_payout,
+			era.fees,
+			era.treasury_remainder,
+			era.minted,
+			era.annual_inflation,
+			era.validator_yield,
+			era.nominator_yield,
+		);
+	}
+}
+
+fn main() {
+	let cli = Cli::parse();
+	let assumptions = Assumptions {
+		params: cli.curve,
+		staking_ratio: cli.staking_ratio,
+		total_issuance: cli.total_issuance,
+		era_duration: cli.era_millis,
+		era_fees: cli.era_fees,
+		target_yield: cli.target_yield,
+		commission: cli.commission,
+		validator_own_ratio: cli.validator_own_ratio,
+	};
+	let eras = cli.eras.unwrap_or_else(|| projection::eras_per_year(cli.era_millis));
+	let eras = projection::project(&assumptions, eras);
+
+	match cli.format {
+		Format::Csv => write_csv(&eras),
+		Format::Json => println!(
+			"{}",
+			serde_json::to_string_pretty(&eras).expect("eras are serializable; qed")
+		),
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn parse_perbill_works() {
+		assert_eq!(parse_perbill("0.5"), Ok(Perbill::from_percent(50)));
+		assert_eq!(parse_perbill("50%"), Ok(Perbill::from_percent(50)));
+		assert_eq!(parse_perbill("12.5%"), Ok(Perbill::from_parts(125_000_000)));
+		assert_eq!(parse_perbill("1"), Ok(Perbill::one()));
+		assert_eq!(parse_perbill("0.000000001"), Ok(Perbill::from_parts(1)));
+		assert!(parse_perbill("1.5").is_err());
+		assert!(parse_perbill("0.0000000001").is_err());
+		assert!(parse_perbill("-0.5").is_err());
+	}
+}
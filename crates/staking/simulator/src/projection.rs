@@ -0,0 +1,191 @@
+//! Era by era projection of the issuance and staking yields, computed with the payout code of
+//! the runtime.
+
+use pallet_staking::{fees::split_fee, inflation, InflationParams};
+use serde::Serialize;
+use sp_runtime::Perbill;
+
+/// The assumptions a projection is made under.
+pub struct Assumptions {
+	/// The inflation curve, as set by `set_inflation_params`.
+	pub params: InflationParams,
+	/// The share of the issuance that is staked, kept constant over the projection.
+	pub staking_ratio: Perbill,
+	/// The issuance at the start of the first era.
+	pub total_issuance: u128,
+	/// The length of an era in milliseconds.
+	pub era_duration: u64,
+	/// The EVM fees paid to stakers in every era.
+	pub era_fees: u128,
+	/// The staking yield above which inflation is offset by fees, like `FeeAwareEraPayout` does.
+	/// `None` projects a plain `InflationCurve`.
+	pub target_yield: Option<Perbill>,
+	/// The commission of validators.
+	pub commission: Perbill,
+	/// The share of the stake backing validators that is their own.
+	pub validator_own_ratio: Perbill,
+}
+
+/// The outcome of a single era.
+#[derive(Clone, PartialEq, Debug, Serialize)]
+pub struct Era {
+	pub era: u32,
+	pub total_issuance: u128,
+	pub total_staked: u128,
+	pub staker_payout: u128,
+	pub fees: u128,
+	pub treasury_remainder: u128,
+	pub minted: u128,
+	/// The yearly inflation if every era minted like this one.
+	pub annual_inflation: f64,
+	/// The yearly yield of validators' own stake, from the payout, commission and fees.
+	pub validator_yield: f64,
+	/// The yearly yield of nominators' stake, from the payout and fees.
+	pub nominator_yield: f64,
+}
+
+/// The number of eras in a year.
+pub fn eras_per_year(era_duration: u64) -> u32 {
+	(inflation::MILLISECONDS_PER_YEAR / era_duration.max(1)) as u32
+}
+
+/// Project `eras` eras under `assumptions`.
+pub fn project(assumptions: &Assumptions, eras: u32) -> Vec<Era> {
+	let Assumptions {
+		params,
+		staking_ratio,
+		mut total_issuance,
+		era_duration,
+		era_fees,
+		target_yield,
+		commission,
+		validator_own_ratio,
+	} = *assumptions;
+	let points = inflation::curve_points(&params);
+	let eras_per_year = inflation::MILLISECONDS_PER_YEAR as f64 / era_duration.max(1) as f64;
+	let yearly = |earned: u128, stake: u128| {
+		if stake == 0 {
+			0.0
+		} else {
+			earned as f64 / stake as f64 * eras_per_year
+		}
+	};
+
+	(0..eras)
+		.map(|era| {
+			let total_staked = staking_ratio * total_issuance;
+			let own = validator_own_ratio * total_staked;
+			let nominated = total_staked - own;
+
+			let (staker_payout, treasury_remainder) = inflation::curve_payout(
+				&params,
+				&points,
+				total_staked,
+				total_issuance,
+				era_duration,
+			);
+			let (staker_payout, treasury_remainder) = match target_yield {
+				Some(target_yield) => inflation::offset_by_fees(
+					staker_payout,
+					treasury_remainder,
+					era_fees,
+					total_staked,
+					target_yield,
+					era_duration,
+				),
+				None => (staker_payout, treasury_remainder),
+			};
+
+			// Split like `make_payout` does: commission first, the rest pro-rata.
+			let validator_commission = commission * staker_payout;
+			let leftover = staker_payout - validator_commission;
+			let validator_payout =
+				validator_commission + Perbill::from_rational(own, total_staked) * leftover;
+			let nominator_payout = staker_payout - validator_payout;
+
+			// Split like `payout_fee_rewards` does for EVM fees, the whole stake being a single
+			// validator with a single nominator.
+			let nominators = if nominated == 0 { None } else { Some(((), nominated)) };
+			let fee_split = split_fee(era_fees, nominated, nominators);
+			let nominator_fees: u128 = fee_split.nominators.iter().map(|(_, share)| share).sum();
+			let validator_fees = fee_split.author + fee_split.remainder;
+
+			// Fees are burned from their payers as they are minted to stakers, only the payout
+			// and the remainder add to the issuance.
+			let minted = staker_payout + treasury_remainder;
+			let outcome = Era {
+				era,
+				total_issuance,
+				total_staked,
+				staker_payout,
+				fees: era_fees,
+				treasury_remainder,
+				minted,
+				annual_inflation: yearly(minted, total_issuance),
+				validator_yield: yearly(validator_payout + validator_fees, own),
+				nominator_yield: yearly(nominator_payout + nominator_fees, nominated),
+			};
+			total_issuance += minted;
+			outcome
+		})
+		.collect()
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	fn assumptions() -> Assumptions {
+		Assumptions {
+			params: InflationParams {
+				min_inflation: Perbill::from_parts(25_000_000),
+				max_inflation: Perbill::from_percent(10),
+				ideal_stake: Perbill::from_percent(50),
+				falloff: Perbill::from_percent(5),
+			},
+			staking_ratio: Perbill::from_percent(50),
+			total_issuance: 1_000_000_000_000_000_000_000_000,
+			era_duration: 24 * 3600 * 1000,
+			era_fees: 0,
+			target_yield: None,
+			commission: Perbill::zero(),
+			validator_own_ratio: Perbill::from_percent(10),
+		}
+	}
+
+	#[test]
+	fn issuance_grows_by_the_max_inflation_at_the_ideal_stake() {
+		let assumptions = assumptions();
+		let eras = project(&assumptions, eras_per_year(assumptions.era_duration));
+
+		let first = &eras[0];
+		assert_eq!(first.fees, 0);
+		assert!((first.annual_inflation - 0.1).abs() < 0.001);
+		// Without commission nor fees everybody earns the same.
+		assert!((first.validator_yield - 0.2).abs() < 0.001);
+		assert!((first.nominator_yield - first.validator_yield).abs() < 0.000_001);
+
+		let last = eras.last().unwrap();
+		// Compounded over the year.
+		assert!(last.total_issuance > first.total_issuance * 110 / 100);
+		assert!(last.total_issuance < first.total_issuance * 111 / 100);
+	}
+
+	#[test]
+	fn fees_offset_inflation_beyond_the_target_yield() {
+		let mut assumptions = assumptions();
+		let plain = project(&assumptions, 1)[0].clone();
+
+		assumptions.era_fees = plain.staker_payout / 2;
+		assumptions.target_yield = Some(Perbill::from_percent(10));
+		let offset = project(&assumptions, 1)[0].clone();
+
+		// Fees don't change the issuance, the payout is moved to the treasury instead.
+		assert_eq!(offset.minted, plain.minted);
+		assert_eq!(offset.staker_payout + assumptions.era_fees, plain.staker_payout);
+		assert_eq!(
+			offset.treasury_remainder,
+			plain.treasury_remainder + plain.staker_payout - offset.staker_payout
+		);
+	}
+}
@@ -43,6 +43,7 @@ use frame_system::RawOrigin;
 const SEED: u32 = 0;
 const MAX_SPANS: u32 = 100;
 const MAX_SLASHES: u32 = 1000;
+const MAX_EXPOSURE_PAGES: u32 = 1000;
 
 type MaxValidators<T> = <<T as Config>::BenchmarkingConfig as BenchmarkingConfig>::MaxValidators;
 type MaxNominators<T> = <<T as Config>::BenchmarkingConfig as BenchmarkingConfig>::MaxNominators;
@@ -546,10 +547,10 @@ benchmarks! {
 	}
 
 	payout_stakers_dead_controller {
-		let n in 0 .. T::MaxNominatorRewardedPerValidator::get() as u32;
+		let n in 0 .. T::MaxExposurePageSize::get() as u32;
 		let (validator, nominators) = create_validator_with_nominators::<T>(
 			n,
-			T::MaxNominatorRewardedPerValidator::get() as u32,
+			T::MaxExposurePageSize::get() as u32,
 			true,
 			RewardDestination::Controller,
 		)?;
@@ -579,10 +580,10 @@ benchmarks! {
 	}
 
 	payout_stakers_alive_staked {
-		let n in 0 .. T::MaxNominatorRewardedPerValidator::get() as u32;
+		let n in 0 .. T::MaxExposurePageSize::get() as u32;
 		let (validator, nominators) = create_validator_with_nominators::<T>(
 			n,
-			T::MaxNominatorRewardedPerValidator::get() as u32,
+			T::MaxExposurePageSize::get() as u32,
 			false,
 			RewardDestination::Staked,
 		)?;
@@ -614,6 +615,48 @@ benchmarks! {
 		}
 	}
 
+	payout_fee_rewards {
+		let n in 1 .. T::MaxExposurePageSize::get() as u32 + 1;
+		// The validator and its `n - 1` nominators share the fees credited to the validator.
+		let (validator, nominators) = create_validator_with_nominators::<T>(
+			n - 1,
+			T::MaxExposurePageSize::get() as u32,
+			false,
+			RewardDestination::Staked,
+		)?;
+
+		let era = CurrentEra::<T>::get().unwrap();
+		let fees = T::Currency::minimum_balance().saturating_mul(1000u32.into());
+		ErasPendingFeeRewards::<T>::insert(era, &validator, fees.saturating_mul(n.into()));
+		// The slashes of the era are applied.
+		let active_era = era + T::SlashDeferDuration::get() + 1;
+		CurrentEra::<T>::put(active_era);
+		ActiveEra::<T>::put(ActiveEraInfo { index: active_era, start: None });
+
+		let caller = whitelisted_caller();
+		let balance_before = T::Currency::free_balance(&validator);
+		let nominator_balances_before = nominators
+			.iter()
+			.map(|(stash, _)| T::Currency::free_balance(stash))
+			.collect::<Vec<_>>();
+	}: _(RawOrigin::Signed(caller), validator.clone(), era)
+	verify {
+		ensure!(
+			ClaimedFeeRewards::<T>::get(era, &validator) == vec![0],
+			"The first page of the fee rewards should have been paid out.",
+		);
+		ensure!(
+			balance_before < T::Currency::free_balance(&validator),
+			"Balance of validator stash should have increased after payout.",
+		);
+		for ((stash, _), balance_before) in nominators.iter().zip(nominator_balances_before.iter()) {
+			ensure!(
+				balance_before < &T::Currency::free_balance(stash),
+				"Balance of nominator stash should have increased after payout.",
+			);
+		}
+	}
+
 	rebond {
 		let l in 1 .. T::MaxUnlockingChunks::get() as u32;
 
@@ -836,6 +879,8 @@ benchmarks! {
 		ConfigOp::Set(u32::MAX),
 		ConfigOp::Set(u32::MAX),
 		ConfigOp::Set(Percent::max_value()),
+		ConfigOp::Set(Perbill::max_value()),
+		ConfigOp::Set(Perbill::max_value()),
 		ConfigOp::Set(Perbill::max_value())
 	) verify {
 		assert_eq!(MinNominatorBond::<T>::get(), BalanceOf::<T>::max_value());
@@ -844,6 +889,8 @@ benchmarks! {
 		assert_eq!(MaxValidatorsCount::<T>::get(), Some(u32::MAX));
 		assert_eq!(ChillThreshold::<T>::get(), Some(Percent::from_percent(100)));
 		assert_eq!(MinCommission::<T>::get(), Perbill::from_percent(100));
+		assert_eq!(MaxCommission::<T>::get(), Some(Perbill::from_percent(100)));
+		assert_eq!(MaxCommissionChangePerEra::<T>::get(), Some(Perbill::from_percent(100)));
 	}
 
 	set_staking_configs_all_remove {
@@ -854,6 +901,8 @@ benchmarks! {
 		ConfigOp::Remove,
 		ConfigOp::Remove,
 		ConfigOp::Remove,
+		ConfigOp::Remove,
+		ConfigOp::Remove,
 		ConfigOp::Remove
 	) verify {
 		assert!(!MinNominatorBond::<T>::exists());
@@ -862,6 +911,8 @@ benchmarks! {
 		assert!(!MaxValidatorsCount::<T>::exists());
 		assert!(!ChillThreshold::<T>::exists());
 		assert!(!MinCommission::<T>::exists());
+		assert!(!MaxCommission::<T>::exists());
+		assert!(!MaxCommissionChangePerEra::<T>::exists());
 	}
 
 	chill_other {
@@ -885,6 +936,8 @@ benchmarks! {
 			ConfigOp::Set(0),
 			ConfigOp::Set(Percent::from_percent(0)),
 			ConfigOp::Set(Zero::zero()),
+			ConfigOp::Remove,
+			ConfigOp::Remove,
 		)?;
 
 		let caller = whitelisted_caller();
@@ -929,6 +982,61 @@ benchmarks! {
 		assert_eq!(MinCommission::<T>::get(), Perbill::from_percent(100));
 	}
 
+	set_inflation_params {
+		let params = InflationParams {
+			min_inflation: Perbill::from_percent(2),
+			max_inflation: Perbill::from_percent(8),
+			ideal_stake: Perbill::from_percent(60),
+			falloff: Perbill::from_percent(5),
+		};
+	}: _(RawOrigin::Root, params)
+	verify {
+		assert_eq!(InflationParameters::<T>::get(), Some(params));
+	}
+
+	move_account {
+		// Slashing Spans
+		let s in 0 .. MAX_SPANS;
+		// Exposure pages of the eras in history
+		let p in 0 .. MAX_EXPOSURE_PAGES;
+		// Clean up any existing state.
+		clear_validators_and_nominators::<T>();
+
+		let origin_weight = MinNominatorBond::<T>::get().max(T::Currency::minimum_balance());
+
+		// Same worst case as `force_unstake`, the stake being re-keyed instead of removed.
+		let scenario = ListScenario::<T>::new(origin_weight, true)?;
+		let controller = scenario.origin_controller1.clone();
+		let stash = scenario.origin_stash1;
+		assert!(T::VoterList::contains(&stash));
+		add_slashing_spans::<T>(&stash, s);
+
+		// Exposure pages of another validator, full of other nominators, to look `stash` up in.
+		#[allow(deprecated)]
+		ErasStakersPaged::<T>::remove_all(None);
+		#[allow(deprecated)]
+		ErasStakers::<T>::remove_all(None);
+		let validator: T::AccountId = account("validator", 0, SEED);
+		let others = (0..T::MaxExposurePageSize::get())
+			.map(|i| IndividualExposure { who: account("nominator", i, SEED), value: 1u32.into() })
+			.collect::<Vec<_>>();
+		let current_era = CurrentEra::<T>::get().unwrap_or(0);
+		for page in 0..p {
+			let exposure = ExposurePage { page_total: 1u32.into(), others: others.clone() };
+			ErasStakersPaged::<T>::insert((current_era, &validator, page), exposure);
+		}
+
+		let to: T::AccountId = account("to", 0, SEED);
+		let from_lookup = T::Lookup::unlookup(stash.clone());
+		let to_lookup = T::Lookup::unlookup(to.clone());
+	}: _(RawOrigin::Root, from_lookup, to_lookup, s, p)
+	verify {
+		assert!(!Ledger::<T>::contains_key(&controller));
+		assert!(!T::VoterList::contains(&stash));
+		assert!(T::VoterList::contains(&to));
+		assert_eq!(Bonded::<T>::get(&to), Some(to.clone()));
+	}
+
 	impl_benchmark_test_suite!(
 		Staking,
 		crate::mock::ExtBuilder::default().has_stakers(true),
@@ -976,7 +1084,7 @@ mod tests {
 
 			let (validator_stash, nominators) = create_validator_with_nominators::<Test>(
 				n,
-				<<Test as Config>::MaxNominatorRewardedPerValidator as Get<_>>::get(),
+				<<Test as Config>::MaxExposurePageSize as Get<_>>::get(),
 				false,
 				RewardDestination::Staked,
 			)
@@ -1005,7 +1113,7 @@ mod tests {
 
 			let (validator_stash, _nominators) = create_validator_with_nominators::<Test>(
 				n,
-				<<Test as Config>::MaxNominatorRewardedPerValidator as Get<_>>::get(),
+				<<Test as Config>::MaxExposurePageSize as Get<_>>::get(),
 				false,
 				RewardDestination::Staked,
 			)
@@ -0,0 +1,65 @@
+//! The split of the fee of an EVM transaction between the author of the block and the nominators
+//! backing it.
+//!
+//! The author receives a tenth of the fee, the other nine tenths go to its nominators in
+//! proportion to their stake. What is left by rounding goes back to the author.
+//!
+//! The fees of an era are credited to the block author as a whole and split when they are paid
+//! out with `payout_fee_rewards`, one page of the exposure of the author at a time. The shares
+//! of the nominators of a page are then computed from their stake out of the whole stake of the
+//! nominators, so the rounding of the shares of each page is not paid out.
+
+use sp_runtime::Perquintill;
+use sp_std::prelude::*;
+
+/// The shares of an EVM transaction fee, as computed by [`split_fee`].
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct FeeSplit<AccountId> {
+	/// The share of the block author for producing the block.
+	pub author: u128,
+	/// The share of each nominator of the block author.
+	pub nominators: Vec<(AccountId, u128)>,
+	/// What is left of the nominators' share after rounding, or all of it when the author has
+	/// no nominators. It goes to the block author as well.
+	pub remainder: u128,
+}
+
+/// Split `fee` between the block author and its `nominators`, given with their stake, which
+/// amounts to `nominator_stake` in total.
+pub fn split_fee<AccountId>(
+	fee: u128,
+	nominator_stake: u128,
+	nominators: impl IntoIterator<Item = (AccountId, u128)>,
+) -> FeeSplit<AccountId> {
+	let author = fee / 10;
+	let nominators_fee = fee.saturating_mul(9) / 10;
+
+	let mut remainder = nominators_fee;
+	let nominators = nominators
+		.into_iter()
+		.map(|(who, stake)| {
+			let share = Perquintill::from_rational(stake, nominator_stake) * nominators_fee;
+			let share = share.min(remainder);
+			remainder -= share;
+			(who, share)
+		})
+		.collect();
+
+	FeeSplit { author, nominators, remainder }
+}
+
+/// Reports the EVM fee rewards paid out by `payout_fee_rewards`.
+pub trait OnFeeRewardPaid<AccountId, Balance> {
+	/// `amount` was paid to `validator` as its share of the fees of the blocks it authored.
+	fn validator_fee_reward(validator: &AccountId, amount: Balance);
+
+	/// `amount` was paid to `nominator` as its share of the fees of the blocks authored by
+	/// `validator`.
+	fn nominator_fee_reward(nominator: &AccountId, validator: &AccountId, amount: Balance);
+}
+
+impl<AccountId, Balance> OnFeeRewardPaid<AccountId, Balance> for () {
+	fn validator_fee_reward(_: &AccountId, _: Balance) {}
+
+	fn nominator_fee_reward(_: &AccountId, _: &AccountId, _: Balance) {}
+}
@@ -19,8 +19,23 @@
 //! the total payout for the era given the era duration and the staking rate in NPoS.
 //! The staking rate in NPoS is the total amount of tokens staked by nominators and validators,
 //! divided by the total token supply.
+//!
+//! It also builds the piecewise-linear yearly inflation curve of [`InflationParams`] at runtime,
+//! for [`crate::InflationCurve`].
 
-use sp_runtime::{curve::PiecewiseLinear, traits::AtLeast32BitUnsigned, Perbill};
+use crate::InflationParams;
+use sp_runtime::{
+	curve::PiecewiseLinear,
+	traits::{AtLeast32BitUnsigned, Saturating},
+	Perbill,
+};
+use sp_std::prelude::*;
+
+/// The number of points of the curves built by [`curve_points`].
+pub const MAX_INFLATION_CURVE_POINTS: u32 = 40;
+
+/// Milliseconds per year for the Julian year (365.25 days).
+pub const MILLISECONDS_PER_YEAR: u64 = 1000 * 3600 * 24 * 36525 / 100;
 
 /// The total payout to all validators (and their nominators) per era and maximum payout.
 ///
@@ -30,7 +45,7 @@ use sp_runtime::{curve::PiecewiseLinear, traits::AtLeast32BitUnsigned, Perbill};
 ///
 /// `era_duration` is expressed in millisecond.
 pub fn compute_total_payout<N>(
-	yearly_inflation: &PiecewiseLinear<'static>,
+	yearly_inflation: &PiecewiseLinear<'_>,
 	npos_token_staked: N,
 	total_tokens: N,
 	era_duration: u64,
@@ -38,10 +53,7 @@ pub fn compute_total_payout<N>(
 where
 	N: AtLeast32BitUnsigned + Clone,
 {
-	// Milliseconds per year for the Julian year (365.25 days).
-	const MILLISECONDS_PER_YEAR: u64 = 1000 * 3600 * 24 * 36525 / 100;
-
-	let portion = Perbill::from_rational(era_duration as u64, MILLISECONDS_PER_YEAR);
+	let portion = era_portion_of_year(era_duration);
 	let payout = portion *
 		yearly_inflation
 			.calculate_for_fraction_times_denominator(npos_token_staked, total_tokens.clone());
@@ -49,6 +61,81 @@ where
 	(payout, maximum)
 }
 
+/// The fraction of a year an era lasting `era_duration` milliseconds represents, with which yearly
+/// rates are turned into era rates.
+pub fn era_portion_of_year(era_duration: u64) -> Perbill {
+	Perbill::from_rational(era_duration as u64, MILLISECONDS_PER_YEAR)
+}
+
+/// The era payout to stakers and "the rest" of the maximum payout, following the yearly
+/// inflation curve of `params`, whose points are `points` as built by [`curve_points`].
+pub fn curve_payout<N>(
+	params: &InflationParams,
+	points: &[(Perbill, Perbill)],
+	total_staked: N,
+	total_issuance: N,
+	era_duration: u64,
+) -> (N, N)
+where
+	N: AtLeast32BitUnsigned + Clone,
+{
+	let curve = PiecewiseLinear { points, maximum: params.max_inflation };
+	let (validator_payout, max_payout) =
+		compute_total_payout(&curve, total_staked, total_issuance, era_duration);
+	let rest = max_payout.saturating_sub(validator_payout.clone());
+	(validator_payout, rest)
+}
+
+/// Offset the era payout to stakers, `validator_payout`, by the EVM `fees` they earned in the
+/// era, as far as both together would yield more than `target_yield` of `total_staked` per year.
+///
+/// Returns the new payout and "the rest", increased by as much as the payout was reduced.
+pub fn offset_by_fees<N>(
+	validator_payout: N,
+	rest: N,
+	fees: N,
+	total_staked: N,
+	target_yield: Perbill,
+	era_duration: u64,
+) -> (N, N)
+where
+	N: AtLeast32BitUnsigned + Clone,
+{
+	let target = era_portion_of_year(era_duration) * (target_yield * total_staked);
+	let excess = validator_payout.clone().saturating_add(fees.clone()).saturating_sub(target);
+	let reduction = excess.min(fees).min(validator_payout.clone());
+
+	(validator_payout.saturating_sub(reduction.clone()), rest.saturating_add(reduction))
+}
+
+/// The points of the piecewise-linear yearly inflation curve of `params`.
+///
+/// The inflation grows linearly from `min_inflation` to `max_inflation` up to the ideal stake,
+/// then decreases as computed by [`pallet_staking_reward_fn::compute_inflation`], sampled at
+/// evenly spaced staking rates.
+///
+/// `params` must be valid, see [`InflationParams::is_valid`].
+pub fn curve_points(params: &InflationParams) -> Vec<(Perbill, Perbill)> {
+	let InflationParams { min_inflation, max_inflation, ideal_stake, falloff } = *params;
+	let inflation_range = max_inflation.saturating_sub(min_inflation);
+	let after_ideal = Perbill::one().saturating_sub(ideal_stake);
+	let pieces = MAX_INFLATION_CURVE_POINTS - 2;
+
+	let mut points = Vec::with_capacity(MAX_INFLATION_CURVE_POINTS as usize);
+	points.push((Perbill::zero(), min_inflation));
+	points.push((ideal_stake, max_inflation));
+	for piece in 1..=pieces {
+		let stake = ideal_stake.saturating_add(Perbill::from_rational(piece, pieces) * after_ideal);
+		let inflation = min_inflation.saturating_add(
+			inflation_range *
+				pallet_staking_reward_fn::compute_inflation(stake, ideal_stake, falloff),
+		);
+		points.push((stake, inflation));
+	}
+	points.dedup_by_key(|(stake, _)| *stake);
+	points
+}
+
 #[cfg(test)]
 mod test {
 	use sp_runtime::curve::PiecewiseLinear;
@@ -105,4 +192,32 @@ mod test {
 			57_038_500_000_000_000_000_000
 		);
 	}
+
+	#[test]
+	fn runtime_curve_matches_compile_time_curve() {
+		use sp_runtime::{assert_eq_error_rate, Perbill};
+
+		const YEAR: u64 = 365 * 24 * 60 * 60 * 1000;
+		let params = crate::InflationParams {
+			min_inflation: Perbill::from_perthousand(25),
+			max_inflation: Perbill::from_percent(10),
+			ideal_stake: Perbill::from_percent(50),
+			falloff: Perbill::from_percent(5),
+		};
+		let points = super::curve_points(&params);
+		assert_eq!(points.len(), super::MAX_INFLATION_CURVE_POINTS as usize);
+		let curve = PiecewiseLinear { points: &points, maximum: params.max_inflation };
+
+		for staked in (0..=100_000u64).step_by(5_000) {
+			assert_eq_error_rate!(
+				super::compute_total_payout(&curve, staked, 100_000u64, YEAR).0,
+				super::compute_total_payout(&I_NPOS, staked, 100_000u64, YEAR).0,
+				100
+			);
+		}
+		assert_eq!(
+			super::compute_total_payout(&curve, 0, 100_000u64, YEAR).1,
+			super::compute_total_payout(&I_NPOS, 0, 100_000u64, YEAR).1,
+		);
+	}
 }
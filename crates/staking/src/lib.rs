@@ -114,9 +114,10 @@
 //!
 //! Rewards must be claimed for each era before it gets too old by `$HISTORY_DEPTH` using the
 //! `payout_stakers` call. Any account can call `payout_stakers`, which pays the reward to the
-//! validator as well as its nominators. Only the [`Config::MaxNominatorRewardedPerValidator`]
-//! biggest stakers can claim their reward. This is to limit the i/o cost to mutate storage for each
-//! nominator's account.
+//! validator as well as its nominators. The nominators of a validator are stored in pages of at
+//! most [`Config::MaxExposurePageSize`] stakers, and each call pays out one page. This is to
+//! limit the i/o cost to mutate storage for each nominator's account, while still rewarding
+//! every nominator.
 //!
 //! Slashing can occur at any point in time, once misbehavior is reported. Once slashing is
 //! determined, a value is deducted from the balance of the validator and all the nominators who
@@ -203,6 +204,10 @@
 //! The remaining reward is send to the configurable end-point
 //! [`Config::RewardRemainder`].
 //!
+//! With [`InflationCurve`] as [`Config::EraPayout`], the parameters of the yearly inflation curve
+//! are kept in storage and can be changed by `T::AdminOrigin` with
+//! [`Call::set_inflation_params`].
+//!
 //! ### Reward Calculation
 //!
 //! Validators and nominators are rewarded at the end of each era. The total reward of an era is
@@ -224,13 +229,11 @@
 //! The validator can declare an amount, named [`commission`](ValidatorPrefs::commission), that does
 //! not get shared with the nominators at each reward payout through its [`ValidatorPrefs`]. This
 //! value gets deducted from the total reward that is paid to the validator and its nominators. The
-//! remaining portion is split pro rata among the validator and the top
-//! [`Config::MaxNominatorRewardedPerValidator`] nominators that nominated the validator,
-//! proportional to the value staked behind the validator (_i.e._ dividing the
-//! [`own`](Exposure::own) or [`others`](Exposure::others) by [`total`](Exposure::total) in
-//! [`Exposure`]). Note that the pro rata division of rewards uses the total exposure behind the
-//! validator, *not* just the exposure of the validator and the top
-//! [`Config::MaxNominatorRewardedPerValidator`] nominators.
+//! remaining portion is split pro rata among the validator and all the nominators that
+//! nominated the validator, proportional to the value staked behind the validator (_i.e._
+//! dividing the [`own`](Exposure::own) or [`others`](Exposure::others) by
+//! [`total`](Exposure::total) in [`Exposure`]). The commission is paid out along with each page
+//! of nominators, in proportion to the stake of the page.
 //!
 //! All entities who receive a reward have the option to choose their reward destination through the
 //! [`Payee`] storage item (see
@@ -292,6 +295,7 @@ pub(crate) mod mock;
 #[cfg(test)]
 mod tests;
 
+pub mod fees;
 pub mod inflation;
 pub mod migrations;
 pub mod slashing;
@@ -733,20 +737,99 @@ impl<AccountId, Balance: Default + HasCompact> Default for Exposure<AccountId, B
 	}
 }
 
+impl<AccountId, Balance: HasCompact + Copy + Ord + Zero + Saturating> Exposure<AccountId, Balance> {
+	/// Split the exposure into its metadata and pages of at most `page_size` nominators, the
+	/// biggest ones first.
+	///
+	/// The own stake of the validator is only counted in the first page, so that the page
+	/// totals add up to the total of the exposure.
+	pub fn into_pages(
+		self,
+		page_size: Page,
+	) -> (PagedExposureMetadata<Balance>, Vec<ExposurePage<AccountId, Balance>>) {
+		let Exposure { total, own, mut others } = self;
+		others.sort_by(|a, b| b.value.cmp(&a.value));
+
+		let page_size = page_size.max(1) as usize;
+		let nominator_count = others.len() as u32;
+		let mut pages = Vec::with_capacity(others.len() / page_size + 1);
+		let mut others = others.into_iter().peekable();
+		while pages.is_empty() || others.peek().is_some() {
+			let page = others.by_ref().take(page_size).collect::<Vec<_>>();
+			let page_total = page.iter().fold(
+				if pages.is_empty() { own } else { Zero::zero() },
+				|total: Balance, individual| total.saturating_add(individual.value),
+			);
+			pages.push(ExposurePage { page_total, others: page });
+		}
+
+		let metadata =
+			PagedExposureMetadata { total, own, nominator_count, page_count: pages.len() as Page };
+		(metadata, pages)
+	}
+}
+
+/// Index of a page of the nominators backing a validator in an era.
+pub type Page = u32;
+
+/// The exposure of a validator in an era, without its nominators, which are stored in pages.
+#[derive(
+	PartialEq,
+	Eq,
+	PartialOrd,
+	Ord,
+	Clone,
+	Encode,
+	Decode,
+	RuntimeDebug,
+	TypeInfo,
+	Default,
+	MaxEncodedLen,
+)]
+pub struct PagedExposureMetadata<Balance: HasCompact> {
+	/// The total balance backing the validator.
+	#[codec(compact)]
+	pub total: Balance,
+	/// The validator's own stash that is exposed.
+	#[codec(compact)]
+	pub own: Balance,
+	/// The number of nominators backing the validator.
+	pub nominator_count: u32,
+	/// The number of pages of nominators. There is always at least one page, possibly empty.
+	pub page_count: Page,
+}
+
+/// A page of the nominators backing a validator in an era.
+#[derive(PartialEq, Eq, PartialOrd, Ord, Clone, Encode, Decode, RuntimeDebug, TypeInfo)]
+pub struct ExposurePage<AccountId, Balance: HasCompact> {
+	/// The stake of the nominators of the page, plus the own stake of the validator for the
+	/// first page.
+	#[codec(compact)]
+	pub page_total: Balance,
+	/// The nominators of the page.
+	pub others: Vec<IndividualExposure<AccountId, Balance>>,
+}
+
+impl<AccountId, Balance: Default + HasCompact> Default for ExposurePage<AccountId, Balance> {
+	fn default() -> Self {
+		Self { page_total: Default::default(), others: vec![] }
+	}
+}
+
 /// A pending slash record. The value of the slash has been computed but not applied yet,
 /// rather deferred for several eras.
 #[derive(Encode, Decode, RuntimeDebug, TypeInfo)]
 pub struct UnappliedSlash<AccountId, Balance: HasCompact> {
 	/// The stash ID of the offending validator.
-	validator: AccountId,
+	pub validator: AccountId,
 	/// The validator's own slash.
-	own: Balance,
+	pub own: Balance,
 	/// All other slashed stakers and amounts.
-	others: Vec<(AccountId, Balance)>,
+	pub others: Vec<(AccountId, Balance)>,
 	/// Reporters of the offence; bounty payout recipients.
-	reporters: Vec<AccountId>,
+	pub reporters: Vec<AccountId>,
 	/// The amount of payout.
-	payout: Balance,
+	pub payout: Balance,
 }
 
 impl<AccountId, Balance: HasCompact + Zero> UnappliedSlash<AccountId, Balance> {
@@ -860,6 +943,111 @@ impl<Balance: AtLeast32BitUnsigned + Clone, T: Get<&'static PiecewiseLinear<'sta
 	}
 }
 
+/// The parameters of the yearly inflation curve of [`InflationCurve`].
+#[derive(PartialEq, Eq, Clone, Copy, Encode, Decode, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+#[cfg_attr(feature = "std", derive(serde::Serialize, serde::Deserialize))]
+pub struct InflationParams {
+	/// The inflation when nothing is staked.
+	pub min_inflation: Perbill,
+	/// The inflation when the ideal fraction of the issuance is staked.
+	pub max_inflation: Perbill,
+	/// The ideal fraction of the issuance to be staked.
+	pub ideal_stake: Perbill,
+	/// How fast inflation decreases when more than the ideal stake is staked, see
+	/// [`pallet_staking_reward_fn::compute_inflation`].
+	pub falloff: Perbill,
+}
+
+impl InflationParams {
+	/// Whether the parameters describe a well-formed curve.
+	pub fn is_valid(&self) -> bool {
+		self.min_inflation <= self.max_inflation &&
+			!self.ideal_stake.is_zero() &&
+			self.ideal_stake < Perbill::one() &&
+			self.falloff >= Perbill::from_percent(1)
+	}
+}
+
+/// An `EraPayout` following the yearly inflation curve of the parameters in
+/// [`InflationParameters`], which can be changed by `T::AdminOrigin` without a runtime upgrade.
+///
+/// `P` are the parameters used until some are set. The points of the curve are computed once
+/// and cached in [`InflationCurvePoints`].
+pub struct InflationCurve<T, P>(sp_std::marker::PhantomData<(T, P)>);
+
+impl<T: Config, P: Get<InflationParams>> InflationCurve<T, P> {
+	/// The parameters of the curve currently in use.
+	pub fn params() -> InflationParams {
+		InflationParameters::<T>::get().unwrap_or_else(P::get)
+	}
+
+	fn points() -> Vec<(Perbill, Perbill)> {
+		let cached = InflationCurvePoints::<T>::get();
+		if !cached.is_empty() {
+			return cached.into_inner()
+		}
+
+		let points = inflation::curve_points(&Self::params());
+		if let Ok(bounded) = BoundedVec::try_from(points.clone()) {
+			InflationCurvePoints::<T>::put(bounded);
+		}
+		points
+	}
+}
+
+impl<T: Config, P: Get<InflationParams>> EraPayout<BalanceOf<T>> for InflationCurve<T, P> {
+	fn era_payout(
+		total_staked: BalanceOf<T>,
+		total_issuance: BalanceOf<T>,
+		era_duration_millis: u64,
+	) -> (BalanceOf<T>, BalanceOf<T>) {
+		inflation::curve_payout(
+			&Self::params(),
+			&Self::points(),
+			total_staked,
+			total_issuance,
+			era_duration_millis,
+		)
+	}
+}
+
+/// An `EraPayout` offsetting the inflation of `I` by the EVM fee rewards of the ending era, see
+/// [`ErasCurrentValidatorReward`].
+///
+/// While inflation and fees together would pay stakers more than `TargetYield` of their stake per
+/// year, the inflation paid to stakers is reduced by the fees, and the difference goes to "the
+/// rest" instead. Inflation is never increased, so stakers may still earn less than the target.
+pub struct FeeAwareEraPayout<T, I, TargetYield>(sp_std::marker::PhantomData<(T, I, TargetYield)>);
+
+impl<T, I, TargetYield> EraPayout<BalanceOf<T>> for FeeAwareEraPayout<T, I, TargetYield>
+where
+	T: Config,
+	I: EraPayout<BalanceOf<T>>,
+	TargetYield: Get<Perbill>,
+{
+	fn era_payout(
+		total_staked: BalanceOf<T>,
+		total_issuance: BalanceOf<T>,
+		era_duration_millis: u64,
+	) -> (BalanceOf<T>, BalanceOf<T>) {
+		let (validator_payout, rest) =
+			I::era_payout(total_staked.clone(), total_issuance, era_duration_millis);
+
+		// The era being ended is still the active one.
+		let fees = Pallet::<T>::active_era()
+			.map(|active_era| Pallet::<T>::era_fee_rewards(active_era.index))
+			.unwrap_or_else(Zero::zero);
+		inflation::offset_by_fees(
+			validator_payout,
+			rest,
+			fees,
+			total_staked,
+			TargetYield::get(),
+			era_duration_millis,
+		)
+	}
+}
+
 /// Mode of era-forcing.
 #[derive(Copy, Clone, PartialEq, Eq, Encode, Decode, RuntimeDebug, TypeInfo, MaxEncodedLen)]
 #[cfg_attr(feature = "std", derive(serde::Serialize, serde::Deserialize))]
@@ -52,6 +52,159 @@ impl Default for ObsoleteReleases {
 #[storage_alias]
 type StorageVersion<T: Config> = StorageValue<Pallet<T>, ObsoleteReleases, ValueQuery>;
 
+pub mod v15 {
+	use super::*;
+
+	/// Re-key the `UnappliedSlashes` deferred by `OldSlashDeferDuration` to the era they are
+	/// applied at with the current `SlashDeferDuration`.
+	///
+	/// `payout_fee_rewards` and `api_unapplied_slashes` derive the era of an offence from the era
+	/// its slashes are applied at, so a change of `SlashDeferDuration` must come with this
+	/// migration. Slashes that are already due with the new duration are applied right away.
+	pub struct MigrateToV15<T, OldSlashDeferDuration>(
+		sp_std::marker::PhantomData<(T, OldSlashDeferDuration)>,
+	);
+	impl<T: Config, OldSlashDeferDuration: Get<EraIndex>> OnRuntimeUpgrade
+		for MigrateToV15<T, OldSlashDeferDuration>
+	{
+		#[cfg(feature = "try-runtime")]
+		fn pre_upgrade() -> Result<Vec<u8>, &'static str> {
+			frame_support::ensure!(
+				Pallet::<T>::on_chain_storage_version() == 14,
+				"Required v14 before upgrading to v15"
+			);
+
+			Ok(Default::default())
+		}
+
+		fn on_runtime_upgrade() -> Weight {
+			let current = Pallet::<T>::current_storage_version();
+			let onchain = Pallet::<T>::on_chain_storage_version();
+
+			if current == 15 && onchain == 14 {
+				let old_defer = OldSlashDeferDuration::get();
+				let new_defer = T::SlashDeferDuration::get();
+				let active_era = Pallet::<T>::active_era().map_or(0, |era| era.index);
+
+				let pending = UnappliedSlashes::<T>::drain().collect::<Vec<_>>();
+				let (mut moved, mut applied) = (0u64, 0u64);
+				for (old_apply_era, slashes) in pending {
+					let offence_era = old_apply_era.saturating_sub(old_defer.saturating_add(1));
+					let apply_era = offence_era.saturating_add(new_defer).saturating_add(1);
+					if apply_era <= active_era {
+						// the slashes would already have been applied with the new duration.
+						for slash in slashes {
+							log!(warn, "applying a slash ({:?}) for era {:?}", slash, offence_era);
+							slashing::apply_slash::<T>(
+								slash,
+								active_era.saturating_sub(new_defer),
+								offence_era,
+							);
+							applied += 1;
+						}
+					} else {
+						moved += 1;
+						UnappliedSlashes::<T>::mutate(apply_era, |for_later| {
+							for_later.extend(slashes)
+						});
+					}
+				}
+				current.put::<Pallet<T>>();
+
+				log!(
+					info,
+					"v15 applied successfully, re-keyed {} eras of slashes, applied {} slashes",
+					moved,
+					applied,
+				);
+				// a slash reads and writes the ledger, balance and spans of every staker it hits.
+				let rw = moved.saturating_mul(2).saturating_add(applied.saturating_mul(64));
+				T::DbWeight::get().reads_writes(rw.saturating_add(3), rw.saturating_add(1))
+			} else {
+				log!(warn, "Skipping v15, should be removed");
+				T::DbWeight::get().reads(1)
+			}
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn post_upgrade(_state: Vec<u8>) -> Result<(), &'static str> {
+			frame_support::ensure!(
+				Pallet::<T>::on_chain_storage_version() == 15,
+				"v15 not applied"
+			);
+
+			let active_era = Pallet::<T>::active_era().map_or(0, |era| era.index);
+			frame_support::ensure!(
+				UnappliedSlashes::<T>::iter_keys().all(|apply_era| apply_era > active_era),
+				"UnappliedSlashes left behind the active era"
+			);
+
+			Ok(())
+		}
+	}
+}
+
+pub mod v14 {
+	use super::*;
+
+	/// Prune the `ErasCurrentValidatorReward` entries of eras that are already out of
+	/// `HistoryDepth`, which `clear_era_information` did not remove before v14.
+	pub struct MigrateToV14<T>(sp_std::marker::PhantomData<T>);
+	impl<T: Config> OnRuntimeUpgrade for MigrateToV14<T> {
+		#[cfg(feature = "try-runtime")]
+		fn pre_upgrade() -> Result<Vec<u8>, &'static str> {
+			frame_support::ensure!(
+				Pallet::<T>::on_chain_storage_version() == 13,
+				"Required v13 before upgrading to v14"
+			);
+
+			Ok(Default::default())
+		}
+
+		fn on_runtime_upgrade() -> Weight {
+			let onchain = Pallet::<T>::on_chain_storage_version();
+
+			if onchain == 13 {
+				let oldest_era =
+					CurrentEra::<T>::get().unwrap_or(0).saturating_sub(T::HistoryDepth::get());
+				let mut reads = 0u64;
+				let stale = ErasCurrentValidatorReward::<T>::iter_keys()
+					.inspect(|_| reads += 1)
+					.filter(|(era, _)| *era < oldest_era)
+					.collect::<Vec<_>>();
+				for (era, validator) in &stale {
+					ErasCurrentValidatorReward::<T>::remove(era, validator);
+				}
+				frame_support::traits::StorageVersion::new(14).put::<Pallet<T>>();
+
+				log!(info, "v14 applied successfully, pruned {} fee reward entries", stale.len());
+				T::DbWeight::get()
+					.reads_writes(reads.saturating_add(3), (stale.len() as u64).saturating_add(1))
+			} else {
+				log!(warn, "Skipping v14, should be removed");
+				T::DbWeight::get().reads(1)
+			}
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn post_upgrade(_state: Vec<u8>) -> Result<(), &'static str> {
+			frame_support::ensure!(
+				Pallet::<T>::on_chain_storage_version() >= 14,
+				"v14 not applied"
+			);
+
+			let oldest_era =
+				CurrentEra::<T>::get().unwrap_or(0).saturating_sub(T::HistoryDepth::get());
+			frame_support::ensure!(
+				ErasCurrentValidatorReward::<T>::iter_keys().all(|(era, _)| era >= oldest_era),
+				"ErasCurrentValidatorReward not pruned"
+			);
+
+			Ok(())
+		}
+	}
+}
+
 pub mod v13 {
 	use super::*;
 
@@ -271,7 +424,7 @@ pub mod v10 {
 						// in the old slashing scheme, the slash era was the key at which we read
 						// from `UnappliedSlashes`.
 						log!(warn, "prematurely applying a slash ({:?}) for era {:?}", slash, era);
-						slashing::apply_slash::<T>(slash, era);
+						slashing::apply_slash::<T>(slash, era, era);
 					}
 				}
 
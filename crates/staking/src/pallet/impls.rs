@@ -21,19 +21,23 @@ use frame_election_provider_support::{
 	data_provider, BoundedSupportsOf, ElectionDataProvider, ElectionProvider, ScoreProvider,
 	SortedListProvider, VoteWeight, VoterOf,
 };
+use codec::EncodeLike;
 use frame_support::{
 	dispatch::WithPostDispatchInfo,
 	pallet_prelude::*,
 	traits::{
-		Currency, CurrencyToVote, Defensive, DefensiveResult, EstimateNextNewSession, Get,
-		Imbalance, LockableCurrency, OnUnbalanced, TryCollect, UnixTime, WithdrawReasons,
+		Currency, CurrencyToVote, Defensive, DefensiveResult, EstimateNextNewSession,
+		ExistenceRequirement, Get, Imbalance, LockableCurrency, OnUnbalanced, TryCollect, UnixTime,
+		WithdrawReasons,
 	},
 	weights::Weight,
 };
 use frame_system::{pallet_prelude::BlockNumberFor, RawOrigin};
 use pallet_session::historical;
 use sp_runtime::{
-	traits::{Bounded, Convert, One, SaturatedConversion, Saturating, StaticLookup, Zero},
+	traits::{
+		Bounded, CheckedAdd, Convert, One, SaturatedConversion, Saturating, StaticLookup, Zero,
+	},
 	Perbill,
 };
 use sp_staking::{
@@ -43,9 +47,11 @@ use sp_staking::{
 use sp_std::prelude::*;
 use sp_runtime::AccountId32;
 use crate::{
+	fees::{self, OnFeeRewardPaid},
 	log, slashing, weights::WeightInfo, ActiveEraInfo, BalanceOf, EraPayout, Exposure, ExposureOf,
-	Forcing, IndividualExposure, MaxWinnersOf, Nominations, PositiveImbalanceOf, RewardDestination,
-	SessionInterface, StakingLedger, ValidatorPrefs,
+	ExposurePage, Forcing, IndividualExposure, MaxWinnersOf, Nominations, Page,
+	PagedExposureMetadata, PositiveImbalanceOf, RewardDestination, SessionInterface,
+	StakingLedger, UnappliedSlash, ValidatorPrefs,
 };
 
 use super::{pallet::*, STAKING_ID};
@@ -135,6 +141,19 @@ impl<T: Config> Pallet<T> {
 	pub(super) fn do_payout_stakers(
 		validator_stash: T::AccountId,
 		era: EraIndex,
+	) -> DispatchResultWithPostInfo {
+		// Errors are reported by `do_payout_stakers_by_page`, in the order of its checks.
+		let page = Self::bonded(&validator_stash)
+			.and_then(Self::ledger)
+			.and_then(|ledger| Self::first_unclaimed_page(era, &ledger))
+			.unwrap_or_default();
+		Self::do_payout_stakers_by_page(validator_stash, era, page)
+	}
+
+	pub(super) fn do_payout_stakers_by_page(
+		validator_stash: T::AccountId,
+		era: EraIndex,
+		page: Page,
 	) -> DispatchResultWithPostInfo {
 		// Validate input data
 		let current_era = CurrentEra::<T>::get().ok_or_else(|| {
@@ -164,24 +183,45 @@ impl<T: Config> Pallet<T> {
 			.claimed_rewards
 			.retain(|&x| x >= current_era.saturating_sub(history_depth));
 
+		let page_count = Self::exposure_page_count(era, &ledger.stash);
+		ensure!(
+			page < page_count,
+			Error::<T>::InvalidPage.with_weight(T::WeightInfo::payout_stakers_alive_staked(0))
+		);
+		let mut claimed_pages = ClaimedRewards::<T>::get(era, &ledger.stash);
+		if claimed_pages.contains(&page) {
+			return Err(Error::<T>::AlreadyClaimed
+				.with_weight(T::WeightInfo::payout_stakers_alive_staked(0)))
+		}
+		claimed_pages.push(page);
+		// The era is only recorded in the ledger once every page is paid out.
+		let claimed_all = claimed_pages.len() as Page >= page_count;
+
 		match ledger.claimed_rewards.binary_search(&era) {
 			Ok(_) =>
 				return Err(Error::<T>::AlreadyClaimed
 					.with_weight(T::WeightInfo::payout_stakers_alive_staked(0))),
-			Err(pos) => ledger
+			Err(pos) if claimed_all => ledger
 				.claimed_rewards
 				.try_insert(pos, era)
 				// Since we retain era entries in `claimed_rewards` only upto
 				// `HistoryDepth`, following bound is always expected to be
 				// satisfied.
 				.defensive_map_err(|_| Error::<T>::BoundNotMet)?,
+			Err(_) => (),
 		}
 
-		let exposure = <ErasStakersClipped<T>>::get(&era, &ledger.stash);
+		let overview = Self::exposure_overview(era, &ledger.stash).unwrap_or_default();
+		let exposure_page = Self::exposure_page(era, &ledger.stash, page).unwrap_or_default();
 
 		// Input data seems good, no errors allowed after this point
 
 		<Ledger<T>>::insert(&controller, &ledger);
+		if claimed_all {
+			ClaimedRewards::<T>::remove(era, &ledger.stash);
+		} else {
+			ClaimedRewards::<T>::insert(era, &ledger.stash, claimed_pages);
+		}
 
 		// Get Era reward points. It has TOTAL and INDIVIDUAL
 		// Find the fraction of the era reward that belongs to the validator
@@ -214,12 +254,23 @@ impl<T: Config> Pallet<T> {
 		let validator_prefs = Self::eras_validator_prefs(&era, &validator_stash);
 		// Validator first gets a cut off the top.
 		let validator_commission = validator_prefs.commission;
-		let validator_commission_payout = validator_commission * validator_total_payout;
+		let validator_total_commission_payout = validator_commission * validator_total_payout;
 
-		let validator_leftover_payout = validator_total_payout - validator_commission_payout;
-		// Now let's calculate how this is split to the validator.
-		let validator_exposure_part = Perbill::from_rational(exposure.own, exposure.total);
-		let validator_staking_payout = validator_exposure_part * validator_leftover_payout;
+		let validator_leftover_payout = validator_total_payout - validator_total_commission_payout;
+		// The commission is paid out along with each page, in proportion to its stake.
+		let page_stake_part = if page_count > 1 {
+			Perbill::from_rational(exposure_page.page_total, overview.total)
+		} else {
+			Perbill::from_percent(100)
+		};
+		let validator_commission_payout = page_stake_part * validator_total_commission_payout;
+		// Now let's calculate how this is split to the validator, whose own stake is in the
+		// first page.
+		let validator_staking_payout = if page == 0 {
+			Perbill::from_rational(overview.own, overview.total) * validator_leftover_payout
+		} else {
+			Zero::zero()
+		};
 
 		Self::deposit_event(Event::<T>::PayoutStarted {
 			era_index: era,
@@ -228,14 +279,15 @@ impl<T: Config> Pallet<T> {
 
 		let mut total_imbalance = PositiveImbalanceOf::<T>::zero();
 		// We can now make total validator payout:
-		if let Some(imbalance) =
-			Self::make_payout(&ledger.stash, validator_staking_payout + validator_commission_payout)
-		{
-			Self::deposit_event(Event::<T>::Rewarded {
-				stash: ledger.stash,
-				amount: imbalance.peek(),
-			});
-			total_imbalance.subsume(imbalance);
+		let validator_payout = validator_staking_payout + validator_commission_payout;
+		if page == 0 || !validator_payout.is_zero() {
+			if let Some(imbalance) = Self::make_payout(&ledger.stash, validator_payout) {
+				Self::deposit_event(Event::<T>::Rewarded {
+					stash: ledger.stash,
+					amount: imbalance.peek(),
+				});
+				total_imbalance.subsume(imbalance);
+			}
 		}
 
 		// Track the number of payout ops to nominators. Note:
@@ -243,10 +295,9 @@ impl<T: Config> Pallet<T> {
 		// out, so we do not need to count their payout op.
 		let mut nominator_payout_count: u32 = 0;
 
-		// Lets now calculate how this is split to the nominators.
-		// Reward only the clipped exposures. Note this is not necessarily sorted.
-		for nominator in exposure.others.iter() {
-			let nominator_exposure_part = Perbill::from_rational(nominator.value, exposure.total);
+		// Lets now calculate how this is split to the nominators of the page.
+		for nominator in exposure_page.others.iter() {
+			let nominator_exposure_part = Perbill::from_rational(nominator.value, overview.total);
 
 			let nominator_reward: BalanceOf<T> =
 				nominator_exposure_part * validator_leftover_payout;
@@ -262,7 +313,7 @@ impl<T: Config> Pallet<T> {
 		}
 
 		T::Reward::on_unbalanced(total_imbalance);
-		debug_assert!(nominator_payout_count <= T::MaxNominatorRewardedPerValidator::get());
+		debug_assert!(nominator_payout_count <= T::MaxExposurePageSize::get());
 		Ok(Some(T::WeightInfo::payout_stakers_alive_staked(nominator_payout_count)).into())
 	}
 
@@ -307,6 +358,100 @@ impl<T: Config> Pallet<T> {
 		}
 	}
 
+	/// Pay an EVM fee reward of `amount` to `stash`, following its `Payee` exactly like era
+	/// rewards are paid. `Staked` rewards are added to the active bond.
+	///
+	/// Returns the amount actually paid, which is zero if the stash is not bonded or does not
+	/// want to be paid.
+	pub fn payout_fee_reward(stash: &T::AccountId, amount: BalanceOf<T>) -> BalanceOf<T> {
+		Self::make_payout(stash, amount)
+			.map(|imbalance| imbalance.peek())
+			.unwrap_or_else(Zero::zero)
+	}
+
+	/// Credit EVM fees of `amount` to the block author `validator` in the active era.
+	///
+	/// The fees are slashed with the validator until the slashes of the era are applied, then
+	/// they can be split between the validator and its nominators and paid out with
+	/// [`Call::payout_fee_rewards`].
+	pub fn credit_fee_reward(
+		validator: &T::AccountId,
+		amount: BalanceOf<T>,
+	) -> Result<(), &'static str> {
+		let active_era = Self::active_era().ok_or("Failed to get era")?.index;
+		ErasPendingFeeRewards::<T>::try_mutate(active_era, validator, |reward| {
+			*reward = reward.checked_add(&amount).ok_or("Overflow while adding rewards")?;
+			Ok(())
+		})
+	}
+
+	pub(super) fn do_payout_fee_rewards(
+		validator_stash: T::AccountId,
+		era: EraIndex,
+	) -> DispatchResultWithPostInfo {
+		let current_era = CurrentEra::<T>::get().ok_or_else(|| {
+			Error::<T>::InvalidEraToReward.with_weight(T::WeightInfo::payout_fee_rewards(0))
+		})?;
+		ensure!(
+			era <= current_era && era >= current_era.saturating_sub(T::HistoryDepth::get()),
+			Error::<T>::InvalidEraToReward.with_weight(T::WeightInfo::payout_fee_rewards(0))
+		);
+		// The slashes of `era` are applied at the start of era `era + SlashDeferDuration + 1`.
+		let active_era = Self::active_era().map_or(0, |active_era| active_era.index);
+		ensure!(
+			era.saturating_add(T::SlashDeferDuration::get()) < active_era,
+			Error::<T>::FeeRewardsLocked.with_weight(T::WeightInfo::payout_fee_rewards(0))
+		);
+
+		let credited = ErasPendingFeeRewards::<T>::get(era, &validator_stash);
+		let claimed_pages = ClaimedFeeRewards::<T>::get(era, &validator_stash);
+		let page = (0..Self::exposure_page_count(era, &validator_stash))
+			.find(|page| !claimed_pages.contains(page))
+			.filter(|_| !credited.is_zero())
+			.ok_or_else(|| {
+				Error::<T>::NoFeeRewards.with_weight(T::WeightInfo::payout_fee_rewards(0))
+			})?;
+		ClaimedFeeRewards::<T>::mutate(era, &validator_stash, |pages| pages.push(page));
+
+		let overview = Self::exposure_overview(era, &validator_stash).unwrap_or_default();
+		let nominators = Self::exposure_page(era, &validator_stash, page)
+			.map(|page| page.others)
+			.unwrap_or_default()
+			.into_iter()
+			.map(|nominator| (nominator.who, nominator.value.saturated_into::<u128>()));
+		let split = fees::split_fee(
+			credited.saturated_into(),
+			overview.total.saturating_sub(overview.own).saturated_into(),
+			nominators,
+		);
+
+		let mut paid = 0u32;
+		if page == 0 {
+			// Without nominators, the validator gets their share as well.
+			let remainder = if overview.nominator_count == 0 { split.remainder } else { 0 };
+			let amount = Self::payout_fee_reward(
+				&validator_stash,
+				split.author.saturating_add(remainder).saturated_into(),
+			);
+			if !amount.is_zero() {
+				T::OnFeeRewardPaid::validator_fee_reward(&validator_stash, amount);
+				let stash = validator_stash.clone();
+				Self::deposit_event(Event::<T>::Rewarded { stash, amount });
+			}
+			paid += 1;
+		}
+		for (nominator, share) in split.nominators {
+			let amount = Self::payout_fee_reward(&nominator, share.saturated_into());
+			if !amount.is_zero() {
+				T::OnFeeRewardPaid::nominator_fee_reward(&nominator, &validator_stash, amount);
+				Self::deposit_event(Event::<T>::Rewarded { stash: nominator, amount });
+			}
+			paid += 1;
+		}
+
+		Ok(Some(T::WeightInfo::payout_fee_rewards(paid)).into())
+	}
+
 	/// Plan a new session potentially trigger a new era.
 	fn new_session(
 		session_index: SessionIndex,
@@ -575,15 +720,7 @@ impl<T: Config> Pallet<T> {
 		let mut total_stake: BalanceOf<T> = Zero::zero();
 		exposures.into_iter().for_each(|(stash, exposure)| {
 			total_stake = total_stake.saturating_add(exposure.total);
-			<ErasStakers<T>>::insert(new_planned_era, &stash, &exposure);
-
-			let mut exposure_clipped = exposure;
-			let clipped_max_len = T::MaxNominatorRewardedPerValidator::get() as usize;
-			if exposure_clipped.others.len() > clipped_max_len {
-				exposure_clipped.others.sort_by(|a, b| a.value.cmp(&b.value).reverse());
-				exposure_clipped.others.truncate(clipped_max_len);
-			}
-			<ErasStakersClipped<T>>::insert(&new_planned_era, &stash, exposure_clipped);
+			Self::set_exposure(new_planned_era, &stash, exposure);
 		});
 
 		// Insert current era staking information
@@ -662,14 +799,179 @@ impl<T: Config> Pallet<T> {
 		<Payee<T>>::remove(stash);
 		Self::do_remove_validator(stash);
 		Self::do_remove_nominator(stash);
+		CommissionAtEraStart::<T>::remove(stash);
+		ChilledCommission::<T>::remove(stash);
 
 		frame_system::Pallet::<T>::dec_consumers(stash);
 
 		Ok(())
 	}
 
+	/// Change the commission of the validator `stash` from `old`, if it is a validator, to `new`.
+	///
+	/// The commission can increase by at most `MaxCommissionChangePerEra` over the active era,
+	/// relative to the commission of the validator at the start of the era. A validator that
+	/// chilled is held to the commission it last had.
+	pub(crate) fn do_change_commission(
+		stash: &T::AccountId,
+		old: Option<Perbill>,
+		new: Perbill,
+	) -> DispatchResult {
+		let active_era = Self::active_era().map_or(0, |active_era| active_era.index);
+		// A validator that chilled is still paid with its commission in the era it is elected.
+		let old = old
+			.or_else(|| {
+				ErasValidatorPrefs::<T>::contains_key(active_era, stash)
+					.then(|| ErasValidatorPrefs::<T>::get(active_era, stash).commission)
+			})
+			.or_else(|| ChilledCommission::<T>::get(stash));
+		let at_era_start = match CommissionAtEraStart::<T>::get(stash) {
+			Some((era, commission)) if era == active_era => Some(commission),
+			_ => old,
+		};
+		if let (Some(at_era_start), Some(max_change)) =
+			(at_era_start, MaxCommissionChangePerEra::<T>::get())
+		{
+			ensure!(
+				new <= at_era_start.saturating_add(max_change),
+				Error::<T>::CommissionChangeTooHigh
+			);
+		}
+
+		if let Some(old) = old.filter(|old| *old != new) {
+			CommissionAtEraStart::<T>::insert(stash, (active_era, at_era_start.unwrap_or(old)));
+			Self::deposit_event(Event::<T>::ValidatorCommissionChanged {
+				stash: stash.clone(),
+				old,
+				new,
+			});
+		}
+		Ok(())
+	}
+
+	/// Ensure that `MinCommission` does not exceed `MaxCommission`.
+	pub(crate) fn ensure_commission_bounds() -> DispatchResult {
+		ensure!(
+			MaxCommission::<T>::get().map_or(true, |max| MinCommission::<T>::get() <= max),
+			Error::<T>::InvalidCommissionBounds
+		);
+		Ok(())
+	}
+
+	/// Whether a slash of `who`, as a validator or a nominator, is waiting to be applied.
+	pub(crate) fn has_unapplied_slash(who: &T::AccountId) -> bool {
+		UnappliedSlashes::<T>::iter_values().any(|slashes| {
+			slashes.iter().any(|slash| {
+				slash.validator == *who || slash.others.iter().any(|(other, _)| other == who)
+			})
+		})
+	}
+
+	/// Whether `who` is exposed, as a validator or a nominator, in an era still in history.
+	///
+	/// Fails with `IncorrectExposurePages` if more than `num_exposure_pages` exposure pages,
+	/// legacy exposures included, must be read to tell.
+	pub(crate) fn is_exposed_in_history(
+		who: &T::AccountId,
+		num_exposure_pages: u32,
+	) -> Result<bool, DispatchError> {
+		let mut pages_left = num_exposure_pages;
+		let mut read_page = || -> DispatchResult {
+			pages_left = pages_left.checked_sub(1).ok_or(Error::<T>::IncorrectExposurePages)?;
+			Ok(())
+		};
+
+		let current_era = Self::current_era().unwrap_or(0);
+		for era in current_era.saturating_sub(T::HistoryDepth::get())..=current_era {
+			for ((validator, _), page) in ErasStakersPaged::<T>::iter_prefix((era,)) {
+				read_page()?;
+				if validator == *who || page.others.iter().any(|i| i.who == *who) {
+					return Ok(true)
+				}
+			}
+			for (validator, exposure) in ErasStakers::<T>::iter_prefix(era) {
+				read_page()?;
+				if validator == *who || exposure.others.iter().any(|i| i.who == *who) {
+					return Ok(true)
+				}
+			}
+		}
+		Ok(false)
+	}
+
+	/// Move the account `from` to `to`, re-keying the staking state of `from` if it is a stash,
+	/// and transfer its free balance, see [`Call::move_account`].
+	///
+	/// Returns the amount transferred.
+	pub(crate) fn do_move_account(
+		from: &T::AccountId,
+		to: &T::AccountId,
+		num_slashing_spans: u32,
+	) -> Result<BalanceOf<T>, DispatchError> {
+		// Take the stake out of `from`, without chilling it.
+		let stake = match Bonded::<T>::take(from) {
+			Some(controller) => {
+				let ledger = Ledger::<T>::take(&controller).ok_or(Error::<T>::BadState)?;
+				slashing::move_stash_metadata::<T>(from, to, num_slashing_spans)?;
+
+				let payee = Payee::<T>::take(from);
+				let prefs = Validators::<T>::contains_key(from).then(|| Validators::<T>::get(from));
+				if let Some(commission) = CommissionAtEraStart::<T>::take(from) {
+					CommissionAtEraStart::<T>::insert(to, commission);
+				}
+				let nominations = Nominators::<T>::get(from);
+				Self::do_remove_validator(from);
+				Self::do_remove_nominator(from);
+				if let Some(commission) = ChilledCommission::<T>::take(from) {
+					ChilledCommission::<T>::insert(to, commission);
+				}
+
+				T::Currency::remove_lock(STAKING_ID, from);
+				frame_system::Pallet::<T>::dec_consumers(from);
+				Some((ledger, payee, prefs, nominations))
+			},
+			None => None,
+		};
+
+		let free = T::Currency::free_balance(from);
+		let amount = if frame_system::Pallet::<T>::can_dec_provider(from) {
+			free
+		} else {
+			free.saturating_sub(T::Currency::minimum_balance())
+		};
+		T::Currency::transfer(from, to, amount, ExistenceRequirement::AllowDeath)?;
+
+		if let Some((mut ledger, payee, prefs, nominations)) = stake {
+			frame_system::Pallet::<T>::inc_consumers(to)?;
+			ledger.stash = to.clone();
+			<Bonded<T>>::insert(to, to);
+			Self::update_ledger(to, &ledger);
+			let payee = match payee {
+				RewardDestination::Account(account) if account == *from =>
+					RewardDestination::Account(to.clone()),
+				payee => payee,
+			};
+			<Payee<T>>::insert(to, payee);
+
+			if let Some(prefs) = prefs {
+				Self::do_add_validator(to, prefs);
+			}
+			if let Some(nominations) = nominations {
+				Self::do_add_nominator(to, nominations);
+			}
+		}
+
+		Ok(amount)
+	}
+
 	/// Clear all era information for given era.
 	pub(crate) fn clear_era_information(era_index: EraIndex) {
+		#[allow(deprecated)]
+		<ErasStakersOverview<T>>::remove_prefix(era_index, None);
+		#[allow(deprecated)]
+		<ErasStakersPaged<T>>::remove_prefix((era_index,), None);
+		#[allow(deprecated)]
+		<ClaimedRewards<T>>::remove_prefix(era_index, None);
 		#[allow(deprecated)]
 		<ErasStakers<T>>::remove_prefix(era_index, None);
 		#[allow(deprecated)]
@@ -677,6 +979,16 @@ impl<T: Config> Pallet<T> {
 		#[allow(deprecated)]
 		<ErasValidatorPrefs<T>>::remove_prefix(era_index, None);
 		<ErasValidatorReward<T>>::remove(era_index);
+		#[allow(deprecated)]
+		<ErasCurrentValidatorReward<T>>::remove_prefix(era_index, None);
+		#[allow(deprecated)]
+		<ErasPendingFeeRewards<T>>::remove_prefix(era_index, None);
+		#[allow(deprecated)]
+		<ClaimedFeeRewards<T>>::remove_prefix(era_index, None);
+		#[allow(deprecated)]
+		<ErasFeeRewardSlash<T>>::remove_prefix(era_index, None);
+		#[allow(deprecated)]
+		<ErasPendingFeeRewardSlash<T>>::remove_prefix(era_index, None);
 		<ErasRewardPoints<T>>::remove(era_index);
 		<ErasTotalStake<T>>::remove(era_index);
 		ErasStartSessionIndex::<T>::remove(era_index);
@@ -693,7 +1005,8 @@ impl<T: Config> Pallet<T> {
 		);
 		for slash in era_slashes {
 			let slash_era = active_era.saturating_sub(T::SlashDeferDuration::get());
-			slashing::apply_slash::<T>(slash, slash_era);
+			// The slashes of an offence are applied `SlashDeferDuration + 1` eras after it.
+			slashing::apply_slash::<T>(slash, slash_era, slash_era.saturating_sub(1));
 		}
 	}
 
@@ -740,7 +1053,7 @@ impl<T: Config> Pallet<T> {
 		stash: T::AccountId,
 		exposure: Exposure<T::AccountId, BalanceOf<T>>,
 	) {
-		<ErasStakers<T>>::insert(&current_era, &stash, &exposure);
+		Self::set_exposure(current_era, &stash, exposure);
 	}
 
 	#[cfg(feature = "runtime-benchmarks")]
@@ -932,6 +1245,7 @@ impl<T: Config> Pallet<T> {
 				.defensive_unwrap_or_default();
 		}
 		Validators::<T>::insert(who, prefs);
+		ChilledCommission::<T>::remove(who);
 
 		debug_assert_eq!(
 			Nominators::<T>::count() + Validators::<T>::count(),
@@ -941,14 +1255,15 @@ impl<T: Config> Pallet<T> {
 
 	/// This function will remove a validator from the `Validators` storage map.
 	///
-	/// Returns true if `who` was removed from `Validators`, otherwise false.
+	/// Returns true if `who` was removed from `Validators`, otherwise false. The commission of
+	/// `who` is kept in `ChilledCommission`.
 	///
 	/// NOTE: you must ALWAYS use this function to remove a validator from the system. Any access to
 	/// `Validators` or `VoterList` outside of this function is almost certainly
 	/// wrong.
 	pub fn do_remove_validator(who: &T::AccountId) -> bool {
 		let outcome = if Validators::<T>::contains_key(who) {
-			Validators::<T>::remove(who);
+			ChilledCommission::<T>::insert(who, Validators::<T>::take(who).commission);
 			let _ = T::VoterList::on_remove(who).defensive();
 			true
 		} else {
@@ -986,6 +1301,177 @@ impl<T: Config> Pallet<T> {
 	pub fn api_nominations_quota(_balance: BalanceOf<T>) -> u32 {
 		T::MaxNominations::get()
 	}
+
+	/// Returns the EVM fee rewards credited to all validators in each era still in history.
+	///
+	/// Used by the runtime API.
+	pub fn api_eras_fee_rewards() -> Vec<(EraIndex, BalanceOf<T>)> {
+		Self::history_eras().map(|era| (era, Self::era_fee_rewards(era))).collect()
+	}
+
+	/// The EVM fee rewards credited to all validators in `era`.
+	pub(crate) fn era_fee_rewards(era: EraIndex) -> BalanceOf<T> {
+		ErasCurrentValidatorReward::<T>::iter_prefix_values(era)
+			.fold(Zero::zero(), |total: BalanceOf<T>, reward| total.saturating_add(reward))
+	}
+
+	/// Returns the EVM fee rewards credited to `validator` in each era still in history.
+	///
+	/// Used by the runtime API.
+	pub fn api_validator_fee_rewards(validator: T::AccountId) -> Vec<(EraIndex, BalanceOf<T>)> {
+		Self::history_eras()
+			.map(|era| {
+				(era, ErasCurrentValidatorReward::<T>::get(era, &validator).unwrap_or_default())
+			})
+			.collect()
+	}
+
+	/// Returns the slashes waiting to be applied, by the era they are applied at, with the era of
+	/// their offence and, for each slash, the EVM fee rewards credited to the slashed validator in
+	/// that era.
+	///
+	/// Used by the runtime API.
+	pub fn api_unapplied_slashes() -> Vec<(
+		EraIndex,
+		EraIndex,
+		Vec<(UnappliedSlash<T::AccountId, BalanceOf<T>>, BalanceOf<T>)>,
+	)> {
+		let mut eras = UnappliedSlashes::<T>::iter()
+			.filter(|(_, slashes)| !slashes.is_empty())
+			.collect::<Vec<_>>();
+		eras.sort_by_key(|(apply_era, _)| *apply_era);
+		eras.into_iter()
+			.map(|(apply_era, slashes)| {
+				// `on_offence` defers slashes to `slash_era + SlashDeferDuration + 1`.
+				let slash_era = apply_era.saturating_sub(T::SlashDeferDuration::get() + 1);
+				let slashes = slashes
+					.into_iter()
+					.map(|slash| {
+						let fee_rewards =
+							ErasCurrentValidatorReward::<T>::get(slash_era, &slash.validator)
+								.unwrap_or_default();
+						(slash, fee_rewards)
+					})
+					.collect();
+				(apply_era, slash_era, slashes)
+			})
+			.collect()
+	}
+
+	/// Returns the payout to stakers and the rest of the maximum payout of `T::EraPayout`, for an
+	/// era lasting `era_duration_millis`, if `staking_ratio` of the current issuance was staked.
+	///
+	/// Used by the runtime API.
+	pub fn api_era_payout(
+		staking_ratio: Perbill,
+		era_duration_millis: u64,
+	) -> (BalanceOf<T>, BalanceOf<T>) {
+		let issuance = T::Currency::total_issuance();
+		T::EraPayout::era_payout(staking_ratio * issuance, issuance, era_duration_millis)
+	}
+
+	/// The eras `[current_era - HistoryDepth, current_era]` era information is kept for.
+	fn history_eras() -> impl Iterator<Item = EraIndex> {
+		let current_era = Self::current_era().unwrap_or(0);
+		current_era.saturating_sub(T::HistoryDepth::get())..=current_era
+	}
+
+	/// Store the exposure of `validator` in `era`, its nominators in pages of at most
+	/// `T::MaxExposurePageSize`.
+	pub fn set_exposure(
+		era: EraIndex,
+		validator: &T::AccountId,
+		exposure: Exposure<T::AccountId, BalanceOf<T>>,
+	) {
+		let (overview, pages) = exposure.into_pages(T::MaxExposurePageSize::get());
+
+		#[allow(deprecated)]
+		<ErasStakersPaged<T>>::remove_prefix((era, validator.clone()), None);
+		for (page, exposure_page) in pages.into_iter().enumerate() {
+			<ErasStakersPaged<T>>::insert((era, validator, page as Page), exposure_page);
+		}
+		<ErasStakersOverview<T>>::insert(era, validator, overview);
+	}
+
+	/// The full exposure of `validator` in `era`, with the nominators of all its pages.
+	///
+	/// This reads every page of the exposure, [`Self::exposure_overview`] and
+	/// [`Self::exposure_page`] should be preferred where they are enough.
+	pub fn eras_stakers<A>(era: EraIndex, validator: A) -> Exposure<T::AccountId, BalanceOf<T>>
+	where
+		A: EncodeLike<T::AccountId> + Clone,
+	{
+		match <ErasStakersOverview<T>>::get(era, validator.clone()) {
+			Some(overview) => Exposure {
+				total: overview.total,
+				own: overview.own,
+				others: (0..overview.page_count)
+					.filter_map(|page| <ErasStakersPaged<T>>::get((era, validator.clone(), page)))
+					.flat_map(|page| page.others)
+					.collect(),
+			},
+			None => <ErasStakers<T>>::get(era, validator),
+		}
+	}
+
+	/// The overview of the exposure of `validator` in `era`.
+	///
+	/// Eras from before exposures were paged are seen as a single page.
+	pub fn exposure_overview(
+		era: EraIndex,
+		validator: &T::AccountId,
+	) -> Option<PagedExposureMetadata<BalanceOf<T>>> {
+		<ErasStakersOverview<T>>::get(era, validator).or_else(|| {
+			<ErasStakers<T>>::contains_key(era, validator).then(|| {
+				let exposure = <ErasStakers<T>>::get(era, validator);
+				PagedExposureMetadata {
+					total: exposure.total,
+					own: exposure.own,
+					nominator_count: exposure.others.len() as u32,
+					page_count: 1,
+				}
+			})
+		})
+	}
+
+	/// A page of the nominators backing `validator` in `era`.
+	///
+	/// For eras from before exposures were paged, the single page is the clipped exposure.
+	pub fn exposure_page(
+		era: EraIndex,
+		validator: &T::AccountId,
+		page: Page,
+	) -> Option<ExposurePage<T::AccountId, BalanceOf<T>>> {
+		if <ErasStakersOverview<T>>::contains_key(era, validator) {
+			return <ErasStakersPaged<T>>::get((era, validator, page))
+		}
+		(page == 0 && <ErasStakersClipped<T>>::contains_key(era, validator)).then(|| {
+			let exposure = <ErasStakersClipped<T>>::get(era, validator);
+			ExposurePage {
+				page_total: exposure
+					.others
+					.iter()
+					.fold(exposure.own, |total, individual| total.saturating_add(individual.value)),
+				others: exposure.others,
+			}
+		})
+	}
+
+	/// The number of pages of the exposure of `validator` in `era`, which is at least one so
+	/// that validators without exposure can still have their (empty) payout claimed.
+	pub(crate) fn exposure_page_count(era: EraIndex, validator: &T::AccountId) -> Page {
+		<ErasStakersOverview<T>>::get(era, validator).map_or(1, |overview| overview.page_count.max(1))
+	}
+
+	/// The first page of the exposure of the validator of `ledger` in `era` whose rewards have
+	/// not been paid out yet, if any.
+	fn first_unclaimed_page(era: EraIndex, ledger: &StakingLedger<T>) -> Option<Page> {
+		if ledger.claimed_rewards.contains(&era) {
+			return None
+		}
+		let claimed_pages = ClaimedRewards::<T>::get(era, &ledger.stash);
+		(0..Self::exposure_page_count(era, &ledger.stash)).find(|page| !claimed_pages.contains(page))
+	}
 }
 
 impl<T: Config> ElectionDataProvider for Pallet<T> {
@@ -1340,6 +1826,12 @@ where
 				slash_era,
 			});
 
+			// The fee rewards of the era are slashed by the highest fraction reported for it.
+			ErasPendingFeeRewardSlash::<T>::mutate(slash_era, stash, |fraction| {
+				*fraction = (*fraction).max(*slash_fraction)
+			});
+			add_db_reads_writes(1, 1);
+
 			if let Some(mut unapplied) = unapplied {
 				let nominators_len = unapplied.others.len() as u64;
 				let reporters_len = details.reporters.len() as u64;
@@ -1352,7 +1844,7 @@ where
 				unapplied.reporters = details.reporters.clone();
 				if slash_defer_duration == 0 {
 					// Apply right away.
-					slashing::apply_slash::<T>(unapplied, slash_era);
+					slashing::apply_slash::<T>(unapplied, slash_era, slash_era);
 					{
 						let slash_cost = (6, 5);
 						let reward_cost = (2, 2);
@@ -1608,7 +2100,10 @@ impl<T: Config> StakingInterface for Pallet<T> {
 	}
 
 	fn is_exposed_in_era(who: &Self::AccountId, era: &EraIndex) -> bool {
-		ErasStakers::<T>::iter_prefix(era).any(|(validator, exposures)| {
+		// Every validator has at least one page, possibly empty.
+		ErasStakersPaged::<T>::iter_prefix((*era,)).any(|((validator, _), page)| {
+			validator == *who || page.others.iter().any(|i| i.who == *who)
+		}) || ErasStakers::<T>::iter_prefix(era).any(|(validator, exposures)| {
 			validator == *who || exposures.others.iter().any(|i| i.who == *who)
 		})
 	}
@@ -1690,7 +2185,7 @@ impl<T: Config> StakingInterface for Pallet<T> {
 				.map(|(who, value)| IndividualExposure { who: who.clone(), value: value.clone() })
 				.collect::<Vec<_>>();
 			let exposure = Exposure { total: Default::default(), own: Default::default(), others };
-			<ErasStakers<T>>::insert(&current_era, &stash, &exposure);
+			Self::set_exposure(*current_era, stash, exposure);
 		}
 
 		fn set_current_era(era: EraIndex) {
@@ -1711,9 +2206,27 @@ impl<T: Config> Pallet<T> {
 		Self::check_nominators()?;
 		Self::check_exposures()?;
 		Self::check_ledgers()?;
+		Self::check_fee_rewards()?;
 		Self::check_count()
 	}
 
+	fn check_fee_rewards() -> Result<(), &'static str> {
+		// fee rewards must be pruned along with the rest of the era information.
+		let current_era = Self::current_era().unwrap_or(0);
+		let oldest_era = current_era.saturating_sub(T::HistoryDepth::get());
+		ensure!(
+			ErasCurrentValidatorReward::<T>::iter_keys()
+				.all(|(era, _)| era >= oldest_era && era <= current_era),
+			"fee rewards of an era out of history."
+		);
+		ensure!(
+			ErasPendingFeeRewards::<T>::iter_keys()
+				.all(|(era, _)| era >= oldest_era && era <= current_era),
+			"pending fee rewards of an era out of history."
+		);
+		Ok(())
+	}
+
 	fn check_count() -> Result<(), &'static str> {
 		ensure!(
 			<T as Config>::VoterList::count() ==
@@ -1741,17 +2254,33 @@ impl<T: Config> Pallet<T> {
 	fn check_exposures() -> Result<(), &'static str> {
 		// a check per validator to ensure the exposure struct is always sane.
 		let era = Self::active_era().unwrap().index;
-		ErasStakers::<T>::iter_prefix_values(era)
-			.map(|expo| {
+		ErasStakersOverview::<T>::iter_prefix(era)
+			.map(|(validator, overview)| {
+				let pages = (0..overview.page_count)
+					.map(|page| ErasStakersPaged::<T>::get((era, &validator, page)))
+					.collect::<Option<Vec<_>>>()
+					.ok_or("missing exposure page.")?;
+				for (index, page) in pages.iter().enumerate() {
+					let own = if index == 0 { overview.own } else { Zero::zero() };
+					ensure!(
+						page.page_total ==
+							own + page.others.iter().fold(Zero::zero(), |acc, e| acc + e.value),
+						"wrong page total exposure.",
+					);
+					ensure!(
+						page.others.len() as u32 <= T::MaxExposurePageSize::get(),
+						"exposure page too big.",
+					);
+				}
 				ensure!(
-					expo.total ==
-						expo.own +
-							expo.others
-								.iter()
-								.map(|e| e.value)
-								.fold(Zero::zero(), |acc, x| acc + x),
+					overview.total == pages.iter().fold(Zero::zero(), |acc, p| acc + p.page_total),
 					"wrong total exposure.",
 				);
+				ensure!(
+					overview.nominator_count as usize ==
+						pages.iter().map(|p| p.others.len()).sum::<usize>(),
+					"wrong nominator count.",
+				);
 				Ok(())
 			})
 			.collect::<Result<_, _>>()
@@ -46,10 +46,11 @@ use sp_runtime::traits::CheckedAdd;
 use crate::ErasValidatorReward;
 use crate::IndividualExposure;
 use crate::{
-	slashing, weights::WeightInfo, AccountIdLookupOf, ActiveEraInfo, BalanceOf, EraPayout,
-	EraRewardPoints, Exposure, Forcing, NegativeImbalanceOf, Nominations, PositiveImbalanceOf,
-	RewardDestination, SessionInterface, StakingLedger, UnappliedSlash, UnlockChunk,
-	ValidatorPrefs, address_mapping::AccountMapping,
+	inflation, slashing, weights::WeightInfo, AccountIdLookupOf, ActiveEraInfo, BalanceOf,
+	EraPayout, EraRewardPoints, Exposure, ExposurePage, Forcing, InflationParams,
+	NegativeImbalanceOf, Nominations, Page,
+	PagedExposureMetadata, PositiveImbalanceOf, RewardDestination, SessionInterface,
+	StakingLedger, UnappliedSlash, UnlockChunk, ValidatorPrefs, address_mapping::AccountMapping,
 };
 use frame_support::traits::FindAuthor;
 use crate::ErasCurrentValidatorReward;
@@ -64,49 +65,35 @@ pub(crate) const SPECULATIVE_NUM_SPANS: u32 = 32;
 pub trait NominatorsHandle<T: pallet::Config + pallet_session::Config> {
 	fn nominators() -> Vec<(<T as frame_system::Config>::AccountId, Nominations<T>)>;
 
-	fn get_nominators_shares(validator: &<T as frame_system::Config>::AccountId) -> Result<Option<Exposure<<T as frame_system::Config>::AccountId, BalanceOf<T>>>, &'static str> ;
-
-	fn author() -> Option<<T as frame_system::Config>::AccountId>;
+	/// The stash of the author of the current block, along with the overview of its exposure in
+	/// the active era.
+	fn author() -> Option<(<T as frame_system::Config>::AccountId, PagedExposureMetadata<BalanceOf<T>>)>;
 
 	fn insert_validator_rewards(validator: &<T as frame_system::Config>::AccountId, rewards: BalanceOf<T>) -> Result<(), &'static str>;
 
 }
 
-impl<T: pallet::Config + pallet_babe::Config + pallet_session::Config> NominatorsHandle<T> for pallet::Pallet<T>
-	where <T as frame_system::Config>::AccountId: From<<T as pallet_session::Config>::ValidatorId> {
+impl<T> NominatorsHandle<T> for pallet::Pallet<T>
+	where
+		T: pallet::Config + pallet_babe::Config + pallet_session::Config,
+		<T as frame_system::Config>::AccountId: From<<T as pallet_session::Config>::ValidatorId> {
 	fn nominators() -> Vec<(<T as frame_system::Config>::AccountId, Nominations<T>)> {
 		pallet::Nominators::<T>::iter().collect()
 	}
 
-	fn author() -> Option<<T as frame_system::Config>::AccountId> {
+	fn author() -> Option<(<T as frame_system::Config>::AccountId, PagedExposureMetadata<BalanceOf<T>>)> {
 		let digest = <frame_system::Pallet<T>>::digest();
 		let pre_runtime_digests = digest.logs.iter().filter_map(|d| d.as_pre_runtime());
-		log::info!("Digest: {:?} {:?}", digest, pre_runtime_digests);
-		if let Some(author_index) = <pallet_babe::Pallet<T>>::find_author(pre_runtime_digests) {
-			let authorities = <pallet_session::Pallet<T>>::validators();
-			log::info!("Validators: {:?}", authorities);
-			let authority_id = authorities[author_index as usize].clone();
-			let validator_id = authority_id.clone().into();
-			for keys in pallet::Bonded::<T>::iter() {
-				log::info!("Keys: {:?}", keys);
-				if keys.0.eq(&validator_id) {
-					return Some(keys.1);
-				}
-			}
-		}
-		None
-	}
+		let author_index = <pallet_babe::Pallet<T>>::find_author(pre_runtime_digests)?;
 
-	fn get_nominators_shares(validator: &<T as frame_system::Config>::AccountId) -> Result<Option<Exposure<<T as frame_system::Config>::AccountId, BalanceOf<T>>>, &'static str> {
-		let current_era = pallet::CurrentEra::<T>::get().ok_or("Failed to get era")?;
+		// Session validators are identified by their stash, whatever their controller is. Only
+		// the overview of the exposure is read here, its nominators are only read on payout.
+		let validator_id = <pallet_session::Pallet<T>>::validators().get(author_index as usize)?.clone();
+		let stash = validator_id.into();
+		let active_era = pallet::Pallet::<T>::active_era()?.index;
+		let overview = pallet::Pallet::<T>::exposure_overview(active_era, &stash)?;
 
-		log::info!("Validator: {:?}", validator);
-		let ledger = <pallet::Pallet<T>>::ledger(validator).ok_or("Failed to get ledger")?.stash;
-		log::info!("Ledger: {:?}", ledger);
-		log::info!("Stakers: {:?}", pallet::ErasStakers::<T>::iter_prefix(current_era).collect::<Vec<_>>());
-		let exp = pallet::ErasStakers::<T>::iter_prefix(current_era).find(|(v, _)| v.eq(&ledger)).map(|exp| exp.1);
-
-		Ok(exp)
+		Some((stash, overview))
 	}
 
 	fn insert_validator_rewards(validator: &<T as frame_system::Config>::AccountId, rewards: BalanceOf<T>) -> Result<(), &'static str> {
@@ -133,7 +120,7 @@ pub mod pallet {
 	use super::*;
 
 	/// The current storage version.
-	const STORAGE_VERSION: StorageVersion = StorageVersion::new(13);
+	const STORAGE_VERSION: StorageVersion = StorageVersion::new(15);
 
 	#[pallet::pallet]
 	#[pallet::generate_store(pub(crate) trait Store)]
@@ -205,10 +192,10 @@ pub mod pallet {
 		/// Number of eras to keep in history.
 		///
 		/// Following information is kept for eras in `[current_era -
-		/// HistoryDepth, current_era]`: `ErasStakers`, `ErasStakersClipped`,
-		/// `ErasValidatorPrefs`, `ErasValidatorReward`, `ErasRewardPoints`,
-		/// `ErasTotalStake`, `ErasStartSessionIndex`,
-		/// `StakingLedger.claimed_rewards`.
+		/// HistoryDepth, current_era]`: `ErasStakersOverview`, `ErasStakersPaged`,
+		/// `ErasStakers`, `ErasStakersClipped`, `ClaimedRewards`, `ErasValidatorPrefs`,
+		/// `ErasValidatorReward`, `ErasCurrentValidatorReward`, `ErasRewardPoints`,
+		/// `ErasTotalStake`, `ErasStartSessionIndex`, `StakingLedger.claimed_rewards`.
 		///
 		/// Must be more than the number of eras delayed by session.
 		/// I.e. active era must always be in history. I.e. `active_era >
@@ -271,12 +258,12 @@ pub mod pallet {
 		/// guess.
 		type NextNewSession: EstimateNextNewSession<Self::BlockNumber>;
 
-		/// The maximum number of nominators rewarded for each validator.
+		/// The maximum number of nominators in a page of the exposure of a validator.
 		///
-		/// For each validator only the `$MaxNominatorRewardedPerValidator` biggest stakers can
-		/// claim their reward. This used to limit the i/o cost for the nominator payout.
+		/// The nominators of a validator are paid out one page at a time, which limits the i/o
+		/// cost of a single payout while rewarding every nominator.
 		#[pallet::constant]
-		type MaxNominatorRewardedPerValidator: Get<u32>;
+		type MaxExposurePageSize: Get<u32>;
 
 		/// The fraction of the validator set that is safe to be offending.
 		/// After the threshold is reached a new era will be forced.
@@ -337,6 +324,9 @@ pub mod pallet {
 		/// Some parameters of the benchmarking.
 		type BenchmarkingConfig: BenchmarkingConfig;
 
+		/// A hook called for every EVM fee reward paid out by [`Call::payout_fee_rewards`].
+		type OnFeeRewardPaid: crate::fees::OnFeeRewardPaid<Self::AccountId, BalanceOf<Self>>;
+
 		/// Weight information for extrinsics in this pallet.
 		type WeightInfo: WeightInfo;
 
@@ -386,6 +376,35 @@ pub mod pallet {
 	#[pallet::storage]
 	pub type MinCommission<T: Config> = StorageValue<_, Perbill, ValueQuery>;
 
+	/// The maximum amount of commission that validators can set.
+	///
+	/// If set to `None`, no limit exists.
+	#[pallet::storage]
+	pub type MaxCommission<T: Config> = StorageValue<_, Perbill, OptionQuery>;
+
+	/// The maximum amount by which validators can increase their commission in an era, relative
+	/// to their commission at the start of the era.
+	///
+	/// If set to `None`, no limit exists.
+	#[pallet::storage]
+	pub type MaxCommissionChangePerEra<T: Config> = StorageValue<_, Perbill, OptionQuery>;
+
+	/// The commission of a validator at the start of the active era in which it last changed
+	/// its commission, along with this era. Keyed by stash.
+	///
+	/// The changes of commission of the validator in this era are limited relative to it, see
+	/// `MaxCommissionChangePerEra`.
+	#[pallet::storage]
+	pub type CommissionAtEraStart<T: Config> =
+		StorageMap<_, Twox64Concat, T::AccountId, (EraIndex, Perbill)>;
+
+	/// The commission of a validator when it last stopped validating. Keyed by stash.
+	///
+	/// The commission of the validator when it validates again is limited relative to it, see
+	/// `MaxCommissionChangePerEra`.
+	#[pallet::storage]
+	pub type ChilledCommission<T: Config> = StorageMap<_, Twox64Concat, T::AccountId, Perbill>;
+
 	/// Map from all (unlocked) "controller" accounts to the info regarding the staking.
 	#[pallet::storage]
 	#[pallet::getter(fn ledger)]
@@ -466,14 +485,17 @@ pub mod pallet {
 	#[pallet::getter(fn eras_start_session_index)]
 	pub type ErasStartSessionIndex<T> = StorageMap<_, Twox64Concat, EraIndex, SessionIndex>;
 
-	/// Exposure of validator at era.
+	/// Exposure of validator at era, from before exposures were paged.
+	///
+	/// This is no longer written to and is only read for eras that have no
+	/// [`ErasStakersOverview`], until they get out of history. Use [`Pallet::eras_stakers`] to
+	/// read the exposure of any era.
 	///
 	/// This is keyed first by the era index to allow bulk deletion and then the stash account.
 	///
 	/// Is it removed after `HISTORY_DEPTH` eras.
 	/// If stakers hasn't been set or has been removed then empty exposure is returned.
 	#[pallet::storage]
-	#[pallet::getter(fn eras_stakers)]
 	#[pallet::unbounded]
 	pub type ErasStakers<T: Config> = StorageDoubleMap<
 		_,
@@ -485,12 +507,11 @@ pub mod pallet {
 		ValueQuery,
 	>;
 
-	/// Clipped Exposure of validator at era.
+	/// Clipped Exposure of validator at era, from before exposures were paged.
 	///
 	/// This is similar to [`ErasStakers`] but number of nominators exposed is reduced to the
-	/// `T::MaxNominatorRewardedPerValidator` biggest stakers.
-	/// (Note: the field `total` and `own` of the exposure remains unchanged).
-	/// This is used to limit the i/o cost for the nominator payout.
+	/// biggest stakers. (Note: the field `total` and `own` of the exposure remains unchanged).
+	/// It is paid out as the single page of the eras that have no [`ErasStakersOverview`].
 	///
 	/// This is keyed fist by the era index to allow bulk deletion and then the stash account.
 	///
@@ -509,6 +530,61 @@ pub mod pallet {
 		ValueQuery,
 	>;
 
+	/// Summary of the exposure of validator at era: its total and own stake and the number of
+	/// pages its nominators are stored in.
+	///
+	/// This is keyed first by the era index to allow bulk deletion and then the stash account.
+	///
+	/// Is it removed after `HISTORY_DEPTH` eras.
+	#[pallet::storage]
+	#[pallet::getter(fn eras_stakers_overview)]
+	pub type ErasStakersOverview<T: Config> = StorageDoubleMap<
+		_,
+		Twox64Concat,
+		EraIndex,
+		Twox64Concat,
+		T::AccountId,
+		PagedExposureMetadata<BalanceOf<T>>,
+		OptionQuery,
+	>;
+
+	/// A page of the nominators backing validator at era, of at most `T::MaxExposurePageSize`
+	/// nominators.
+	///
+	/// This is keyed by the era index, the stash account and the page index.
+	///
+	/// Is it removed after `HISTORY_DEPTH` eras.
+	#[pallet::storage]
+	#[pallet::unbounded]
+	pub type ErasStakersPaged<T: Config> = StorageNMap<
+		_,
+		(
+			NMapKey<Twox64Concat, EraIndex>,
+			NMapKey<Twox64Concat, T::AccountId>,
+			NMapKey<Twox64Concat, Page>,
+		),
+		ExposurePage<T::AccountId, BalanceOf<T>>,
+		OptionQuery,
+	>;
+
+	/// The pages of the exposure of validator at era whose rewards have been paid out, until all
+	/// of them are. The era is then recorded in `StakingLedger.claimed_rewards`.
+	///
+	/// This is keyed first by the era index to allow bulk deletion and then the stash account.
+	///
+	/// Is it removed after `HISTORY_DEPTH` eras.
+	#[pallet::storage]
+	#[pallet::unbounded]
+	pub type ClaimedRewards<T: Config> = StorageDoubleMap<
+		_,
+		Twox64Concat,
+		EraIndex,
+		Twox64Concat,
+		T::AccountId,
+		Vec<Page>,
+		ValueQuery,
+	>;
+
 	/// Similar to `ErasStakers`, this holds the preferences of validators.
 	///
 	/// This is keyed first by the era index to allow bulk deletion and then the stash account.
@@ -534,6 +610,10 @@ pub mod pallet {
 	#[pallet::getter(fn eras_validator_reward)]
 	pub type ErasValidatorReward<T: Config> = StorageMap<_, Twox64Concat, EraIndex, BalanceOf<T>>;
 
+	/// EVM fee rewards credited to each validator in the last `HISTORY_DEPTH` eras.
+	///
+	/// Written as fees are distributed, so the entries of the current era keep growing until
+	/// the era ends.
 	#[pallet::storage]
 	#[pallet::getter(fn eras_current_validator_reward)]
 	pub type ErasCurrentValidatorReward<T: Config> = StorageDoubleMap<
@@ -545,6 +625,70 @@ pub mod pallet {
 		BalanceOf<T>
 	>;
 
+	/// The EVM fees credited to a validator in an era, which are not paid out yet.
+	///
+	/// They are split between the validator and the nominators of its exposure in the era when
+	/// they are paid out with [`Call::payout_fee_rewards`], page by page, once the slashes of the
+	/// era are applied. They are slashed with the validator until then.
+	///
+	/// It is removed after `HISTORY_DEPTH` eras.
+	#[pallet::storage]
+	pub type ErasPendingFeeRewards<T: Config> = StorageDoubleMap<
+		_,
+		Twox64Concat,
+		EraIndex,
+		Twox64Concat,
+		T::AccountId,
+		BalanceOf<T>,
+		ValueQuery,
+	>;
+
+	/// The pages of the exposure of a validator in an era whose EVM fee rewards have been paid
+	/// out.
+	///
+	/// It is removed after `HISTORY_DEPTH` eras.
+	#[pallet::storage]
+	#[pallet::unbounded]
+	pub type ClaimedFeeRewards<T: Config> = StorageDoubleMap<
+		_,
+		Twox64Concat,
+		EraIndex,
+		Twox64Concat,
+		T::AccountId,
+		Vec<Page>,
+		ValueQuery,
+	>;
+
+	/// The fraction of the pending EVM fee rewards of a validator in an era that was slashed.
+	///
+	/// A later slash of the validator for the same era only slashes the difference.
+	#[pallet::storage]
+	pub type ErasFeeRewardSlash<T: Config> = StorageDoubleMap<
+		_,
+		Twox64Concat,
+		EraIndex,
+		Twox64Concat,
+		T::AccountId,
+		Perbill,
+		ValueQuery,
+	>;
+
+	/// The highest slash fraction reported for a validator in an era, which the pending EVM fee
+	/// rewards of the era are slashed by once the slash is applied.
+	///
+	/// It is recorded when the offence is reported since the slashing metadata of the era may be
+	/// pruned before the deferred slash is applied.
+	#[pallet::storage]
+	pub type ErasPendingFeeRewardSlash<T: Config> = StorageDoubleMap<
+		_,
+		Twox64Concat,
+		EraIndex,
+		Twox64Concat,
+		T::AccountId,
+		Perbill,
+		ValueQuery,
+	>;
+
 	/// Rewards for the last `HISTORY_DEPTH` eras.
 	/// If reward hasn't been set or has been removed then 0 reward is returned.
 	#[pallet::storage]
@@ -660,6 +804,23 @@ pub mod pallet {
 	#[pallet::storage]
 	pub(crate) type ChillThreshold<T: Config> = StorageValue<_, Percent, OptionQuery>;
 
+	/// The parameters of the yearly inflation curve of [`crate::InflationCurve`].
+	///
+	/// The default parameters of the runtime are used until some are set by
+	/// `set_inflation_params`.
+	#[pallet::storage]
+	#[pallet::getter(fn inflation_params)]
+	pub type InflationParameters<T> = StorageValue<_, InflationParams, OptionQuery>;
+
+	/// The points of the piecewise-linear curve of the inflation parameters in use, computed on
+	/// first use and cleared when the parameters change.
+	#[pallet::storage]
+	pub type InflationCurvePoints<T> = StorageValue<
+		_,
+		BoundedVec<(Perbill, Perbill), ConstU32<{ inflation::MAX_INFLATION_CURVE_POINTS }>>,
+		ValueQuery,
+	>;
+
 	#[pallet::genesis_config]
 	pub struct GenesisConfig<T: Config> {
 		pub validator_count: u32,
@@ -795,8 +956,15 @@ pub mod pallet {
 		PayoutStarted { era_index: EraIndex, validator_stash: T::AccountId },
 		/// A validator has set their preferences.
 		ValidatorPrefsSet { stash: T::AccountId, prefs: ValidatorPrefs },
+		/// The commission of a validator was changed from `old` to `new`.
+		ValidatorCommissionChanged { stash: T::AccountId, old: Perbill, new: Perbill },
 		/// A new force era mode was set.
 		ForceEra { mode: Forcing },
+		/// The parameters of the inflation curve were changed.
+		InflationParamsSet { params: InflationParams },
+		/// An account was moved to another account, with its stake if it was a stash and
+		/// `amount` of its free balance.
+		AccountMoved { from: T::AccountId, to: T::AccountId, amount: BalanceOf<T> },
 	}
 
 	#[pallet::error]
@@ -833,6 +1001,8 @@ pub mod pallet {
 		NotSortedAndUnique,
 		/// Rewards for this era have already been claimed for this validator.
 		AlreadyClaimed,
+		/// No page of the exposure of this validator has this index.
+		InvalidPage,
 		/// Incorrect previous history depth input provided.
 		IncorrectHistoryDepth,
 		/// Incorrect number of slashing spans provided.
@@ -853,8 +1023,32 @@ pub mod pallet {
 		TooManyValidators,
 		/// Commission is too low. Must be at least `MinCommission`.
 		CommissionTooLow,
+		/// Commission is too high. Must be at most `MaxCommission`.
+		CommissionTooHigh,
+		/// Commission is increased too much. It can increase by at most
+		/// `MaxCommissionChangePerEra` in an era.
+		CommissionChangeTooHigh,
+		/// `MinCommission` cannot exceed `MaxCommission`.
+		InvalidCommissionBounds,
 		/// Some bound is not met.
 		BoundNotMet,
+		/// The inflation parameters do not describe a valid curve: the minimum inflation must not
+		/// exceed the maximum, the ideal stake must be strictly between 0 and 100% and the falloff
+		/// at least 1%.
+		InvalidInflationParams,
+		/// An account cannot be moved to itself.
+		SameAccount,
+		/// A slash of the account is pending to be applied.
+		PendingSlash,
+		/// The EVM fee rewards of this era can still be slashed and cannot be paid out yet.
+		FeeRewardsLocked,
+		/// There are no EVM fee rewards to pay out.
+		NoFeeRewards,
+		/// The account is exposed in an era still in history, whose rewards and slashes are
+		/// keyed by it.
+		ExposedInHistory,
+		/// Incorrect number of exposure pages provided.
+		IncorrectExposurePages,
 	}
 
 	#[pallet::hooks]
@@ -902,6 +1096,16 @@ pub mod pallet {
 						T::BondingDuration::get(),
 					)
 				);
+				// EVM fee rewards are paid out after the slashes of their era, which must happen
+				// before the era is pruned.
+				sp_io::TestExternalities::new_empty().execute_with(||
+					assert!(
+						T::SlashDeferDuration::get() < T::HistoryDepth::get(),
+						"Slash defer duration ({}) should be less than history depth ({}).",
+						T::SlashDeferDuration::get(),
+						T::HistoryDepth::get(),
+					)
+				);
 			}
 		}
 
@@ -1192,6 +1396,10 @@ pub mod pallet {
 
 			// ensure their commission is correct.
 			ensure!(prefs.commission >= MinCommission::<T>::get(), Error::<T>::CommissionTooLow);
+			ensure!(
+				MaxCommission::<T>::get().map_or(true, |max| prefs.commission <= max),
+				Error::<T>::CommissionTooHigh
+			);
 
 			// Only check limits if they are not already a validator.
 			if !Validators::<T>::contains_key(stash) {
@@ -1206,6 +1414,10 @@ pub mod pallet {
 				}
 			}
 
+			let old_commission = Validators::<T>::contains_key(stash)
+				.then(|| Validators::<T>::get(stash).commission);
+			Self::do_change_commission(stash, old_commission, prefs.commission)?;
+
 			Self::do_remove_nominator(stash);
 			Self::do_add_validator(stash, prefs.clone());
 			Self::deposit_event(Event::<T>::ValidatorPrefsSet { stash: ledger.stash, prefs });
@@ -1552,20 +1764,23 @@ pub mod pallet {
 			Ok(())
 		}
 
-		/// Pay out all the stakers behind a single validator for a single era.
+		/// Pay out the next page of the stakers behind a single validator for a single era.
 		///
-		/// - `validator_stash` is the stash account of the validator. Their nominators, up to
-		///   `T::MaxNominatorRewardedPerValidator`, will also receive their rewards.
+		/// - `validator_stash` is the stash account of the validator. Their nominators in the
+		///   page, up to `T::MaxExposurePageSize`, will also receive their rewards.
 		/// - `era` may be any era between `[current_era - history_depth; current_era]`.
 		///
+		/// The call has to be repeated once per page of the exposure of the validator, see
+		/// [`Pallet::eras_stakers_overview`], to pay out all of its nominators.
+		///
 		/// The origin of this call must be _Signed_. Any account can call this function, even if
 		/// it is not one of the stakers.
 		///
 		/// ## Complexity
-		/// - At most O(MaxNominatorRewardedPerValidator).
+		/// - At most O(MaxExposurePageSize).
 		#[pallet::call_index(18)]
 		#[pallet::weight(T::WeightInfo::payout_stakers_alive_staked(
-			T::MaxNominatorRewardedPerValidator::get()
+			T::MaxExposurePageSize::get()
 		))]
 		pub fn payout_stakers(
 			origin: OriginFor<T>,
@@ -1702,6 +1917,11 @@ pub mod pallet {
 		///   should be filled in order for the `chill_other` transaction to work.
 		/// * `min_commission`: The minimum amount of commission that each validators must maintain.
 		///   This is checked only upon calling `validate`. Existing validators are not affected.
+		/// * `max_commission`: The maximum amount of commission that validators can set. When set
+		///   to `None`, no limit is enforced. It cannot be less than `min_commission`. This is
+		///   checked only upon calling `validate`. Existing validators are not affected.
+		/// * `max_commission_change_per_era`: The maximum amount by which validators can increase
+		///   their commission in an era. When set to `None`, no limit is enforced.
 		///
 		/// RuntimeOrigin must be Root to call this function.
 		///
@@ -1722,6 +1942,8 @@ pub mod pallet {
 			max_validator_count: ConfigOp<u32>,
 			chill_threshold: ConfigOp<Percent>,
 			min_commission: ConfigOp<Perbill>,
+			max_commission: ConfigOp<Perbill>,
+			max_commission_change_per_era: ConfigOp<Perbill>,
 		) -> DispatchResult {
 			ensure_root(origin)?;
 
@@ -1741,7 +1963,9 @@ pub mod pallet {
 			config_op_exp!(MaxValidatorsCount<T>, max_validator_count);
 			config_op_exp!(ChillThreshold<T>, chill_threshold);
 			config_op_exp!(MinCommission<T>, min_commission);
-			Ok(())
+			config_op_exp!(MaxCommission<T>, max_commission);
+			config_op_exp!(MaxCommissionChangePerEra<T>, max_commission_change_per_era);
+			Self::ensure_commission_bounds()
 		}
 		/// Declare a `controller` to stop participating as either a validator or nominator.
 		///
@@ -1840,19 +2064,27 @@ pub mod pallet {
 		) -> DispatchResult {
 			ensure_signed(origin)?;
 			let min_commission = MinCommission::<T>::get();
-			Validators::<T>::try_mutate_exists(validator_stash, |maybe_prefs| {
+			let old = Validators::<T>::try_mutate_exists(&validator_stash, |maybe_prefs| {
 				maybe_prefs
 					.as_mut()
 					.map(|prefs| {
 						(prefs.commission < min_commission)
-							.then(|| prefs.commission = min_commission)
+							.then(|| sp_std::mem::replace(&mut prefs.commission, min_commission))
 					})
 					.ok_or(Error::<T>::NotStash)
 			})?;
+			if let Some(old) = old {
+				Self::deposit_event(Event::<T>::ValidatorCommissionChanged {
+					stash: validator_stash,
+					old,
+					new: min_commission,
+				});
+			}
 			Ok(())
 		}
 
-		/// Sets the minimum amount of commission that each validators must maintain.
+		/// Sets the minimum amount of commission that each validators must maintain. It cannot
+		/// exceed `MaxCommission`.
 		///
 		/// This call has lower privilege requirements than `set_staking_config` and can be called
 		/// by the `T::AdminOrigin`. Root can always call this.
@@ -1861,8 +2093,138 @@ pub mod pallet {
 		pub fn set_min_commission(origin: OriginFor<T>, new: Perbill) -> DispatchResult {
 			T::AdminOrigin::ensure_origin(origin)?;
 			MinCommission::<T>::put(new);
+			Self::ensure_commission_bounds()
+		}
+
+		/// Pay out a given page of the stakers behind a single validator for a single era.
+		///
+		/// Same as [`payout_stakers`](Self::payout_stakers), but for an explicit `page`, so that
+		/// pages can be paid out in any order or in parallel.
+		///
+		/// ## Complexity
+		/// - At most O(MaxExposurePageSize).
+		#[pallet::call_index(26)]
+		#[pallet::weight(T::WeightInfo::payout_stakers_alive_staked(
+			T::MaxExposurePageSize::get()
+		))]
+		pub fn payout_stakers_by_page(
+			origin: OriginFor<T>,
+			validator_stash: T::AccountId,
+			era: EraIndex,
+			page: Page,
+		) -> DispatchResultWithPostInfo {
+			ensure_signed(origin)?;
+			Self::do_payout_stakers_by_page(validator_stash, era, page)
+		}
+
+		/// Set the parameters of the yearly inflation curve, used by the era payout when the
+		/// runtime uses [`crate::InflationCurve`].
+		///
+		/// Takes effect at the end of the current era. Can be called by the `T::AdminOrigin`.
+		///
+		/// Emits `InflationParamsSet`.
+		#[pallet::call_index(27)]
+		#[pallet::weight(T::WeightInfo::set_inflation_params())]
+		pub fn set_inflation_params(origin: OriginFor<T>, params: InflationParams) -> DispatchResult {
+			T::AdminOrigin::ensure_origin(origin)?;
+			ensure!(params.is_valid(), Error::<T>::InvalidInflationParams);
+
+			InflationParameters::<T>::put(params);
+			InflationCurvePoints::<T>::kill();
+			Self::deposit_event(Event::<T>::InflationParamsSet { params });
 			Ok(())
 		}
+
+		/// Move the account `from`, whose keys were lost, to `to`.
+		///
+		/// If `from` is a stash, its stake is moved rather than unstaked: its ledger, validator
+		/// preferences or nominations, reward destination and slashing spans are re-keyed to
+		/// `to`, which becomes its own controller. Then the free balance of `from` is transferred
+		/// to `to`, leaving the existential deposit behind if `from` cannot be reaped.
+		///
+		/// The exposures, rewards and slashes of an era stay keyed by the stakers elected in it,
+		/// so `from` cannot be moved while it is exposed, as a validator or a nominator, in an era
+		/// still in history: a validator must be chilled and a nominator stop nominating
+		/// `HistoryDepth` eras before. Nor can it be moved while a slash of it is pending, nor to
+		/// an account that is already staking.
+		///
+		/// The dispatch origin must be `T::AdminOrigin`. `num_slashing_spans` is the number of
+		/// slashing spans of `from` if it is a stash, and `num_exposure_pages` the number of
+		/// exposure pages, legacy exposures included, of the eras still in history.
+		///
+		/// Emits `AccountMoved`.
+		#[pallet::call_index(28)]
+		#[pallet::weight(T::WeightInfo::move_account(*num_slashing_spans, *num_exposure_pages))]
+		pub fn move_account(
+			origin: OriginFor<T>,
+			from: AccountIdLookupOf<T>,
+			to: AccountIdLookupOf<T>,
+			num_slashing_spans: u32,
+			num_exposure_pages: u32,
+		) -> DispatchResult {
+			T::AdminOrigin::ensure_origin(origin)?;
+			let from = T::Lookup::lookup(from)?;
+			let to = T::Lookup::lookup(to)?;
+
+			ensure!(from != to, Error::<T>::SameAccount);
+			ensure!(!Bonded::<T>::contains_key(&to), Error::<T>::AlreadyBonded);
+			ensure!(!Ledger::<T>::contains_key(&to), Error::<T>::AlreadyPaired);
+			// A controller is moved with its stash.
+			ensure!(
+				Self::ledger(&from).map_or(true, |ledger| ledger.stash == from),
+				Error::<T>::NotStash
+			);
+			ensure!(!Self::has_unapplied_slash(&from), Error::<T>::PendingSlash);
+			ensure!(
+				!Self::is_exposed_in_history(&from, num_exposure_pages)?,
+				Error::<T>::ExposedInHistory
+			);
+
+			let amount = Self::do_move_account(&from, &to, num_slashing_spans)?;
+
+			// Nothing of `from` must be left at stake.
+			ensure!(
+				!Bonded::<T>::contains_key(&from) &&
+					!Payee::<T>::contains_key(&from) &&
+					!Validators::<T>::contains_key(&from) &&
+					!Nominators::<T>::contains_key(&from) &&
+					!SlashingSpans::<T>::contains_key(&from) &&
+					T::Currency::free_balance(&from) <= T::Currency::minimum_balance(),
+				Error::<T>::BadState
+			);
+
+			Self::deposit_event(Event::<T>::AccountMoved { from, to, amount });
+			Ok(())
+		}
+
+		/// Pay out the EVM fee rewards of the stakers behind a single validator for a single era.
+		///
+		/// - `validator_stash` is the stash account of the validator. The fees credited to it are
+		///   split between the nominators of a page of its exposure per call, the first page
+		///   paying the validator its own share as well.
+		/// - `era` may be any era of the history whose slashes are applied, that is before the
+		///   last `SlashDeferDuration + 1` eras.
+		///
+		/// The call has to be repeated until it fails with `NoFeeRewards` to pay out all of the
+		/// pages. The rewards are paid following the `Payee` of each staker.
+		///
+		/// The origin of this call must be _Signed_. Any account can call this function, even if
+		/// it is not one of the stakers.
+		///
+		/// ## Complexity
+		/// - At most O(MaxExposurePageSize).
+		#[pallet::call_index(29)]
+		#[pallet::weight(
+			T::WeightInfo::payout_fee_rewards(T::MaxExposurePageSize::get().saturating_add(1))
+		)]
+		pub fn payout_fee_rewards(
+			origin: OriginFor<T>,
+			validator_stash: T::AccountId,
+			era: EraIndex,
+		) -> DispatchResultWithPostInfo {
+			ensure_signed(origin)?;
+			Self::do_payout_fee_rewards(validator_stash, era)
+		}
 	}
 }
 
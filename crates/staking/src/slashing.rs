@@ -50,8 +50,8 @@
 //! Based on research at <https://research.web3.foundation/en/latest/polkadot/slashing/npos.html>
 
 use crate::{
-	BalanceOf, Config, Error, Exposure, NegativeImbalanceOf, Pallet, Perbill, SessionInterface,
-	Store, UnappliedSlash,
+	BalanceOf, Config, Error, ErasFeeRewardSlash, ErasPendingFeeRewardSlash, ErasPendingFeeRewards,
+	Exposure, NegativeImbalanceOf, Pallet, Perbill, SessionInterface, Store, UnappliedSlash,
 };
 use codec::{Decode, Encode, MaxEncodedLen};
 use frame_support::{
@@ -61,7 +61,7 @@ use frame_support::{
 use scale_info::TypeInfo;
 use sp_runtime::{
 	traits::{Saturating, Zero},
-	DispatchResult, RuntimeDebug,
+	DispatchResult, PerThing, RuntimeDebug,
 };
 use sp_staking::{offence::DisableStrategy, EraIndex};
 use sp_std::vec::Vec;
@@ -588,6 +588,31 @@ pub(crate) fn clear_stash_metadata<T: Config>(
 	Ok(())
 }
 
+/// Move the slashing-span metadata of the stash `from` to `to`, which must have none.
+pub(crate) fn move_stash_metadata<T: Config>(
+	from: &T::AccountId,
+	to: &T::AccountId,
+	num_slashing_spans: u32,
+) -> DispatchResult {
+	let spans = match <Pallet<T> as Store>::SlashingSpans::take(from) {
+		None => return Ok(()),
+		Some(s) => s,
+	};
+
+	ensure!(
+		num_slashing_spans as usize >= spans.iter().count(),
+		Error::<T>::IncorrectSlashingSpans
+	);
+
+	for span in spans.iter() {
+		let record = <Pallet<T> as Store>::SpanSlash::take(&(from.clone(), span.index));
+		<Pallet<T> as Store>::SpanSlash::insert(&(to.clone(), span.index), record);
+	}
+	<Pallet<T> as Store>::SlashingSpans::insert(to, spans);
+
+	Ok(())
+}
+
 // apply the slash to a stash account, deducting any missing funds from the reward
 // payout, saturating at 0. this is mildly unfair but also an edge-case that
 // can only occur when overlapping locked funds have been slashed.
@@ -629,12 +654,47 @@ pub fn do_slash<T: Config>(
 	}
 }
 
-/// Apply a previously-unapplied slash.
+/// Slash the EVM fees credited to `validator` in `offence_era`, which are shared with its
+/// nominators, by the slash fraction of the validator in this era.
+///
+/// The fraction is the highest one reported for the era, like for the stake, as recorded in
+/// `ErasPendingFeeRewardSlash` by `on_offence`. Only the part of it that was not applied to the
+/// fee rewards yet is slashed, the rest of the rewards being already gone.
+fn slash_fee_rewards<T: Config>(
+	validator: &T::AccountId,
+	offence_era: EraIndex,
+) -> NegativeImbalanceOf<T> {
+	let fraction = ErasPendingFeeRewardSlash::<T>::get(offence_era, validator);
+	let applied = ErasFeeRewardSlash::<T>::get(offence_era, validator);
+	if fraction <= applied {
+		return NegativeImbalanceOf::<T>::zero()
+	}
+	// `1 - applied` of the rewards are left, of which `fraction - applied` are slashed.
+	let slash = Perbill::from_rational(
+		(fraction - applied).deconstruct(),
+		(Perbill::one() - applied).deconstruct(),
+	);
+
+	let slashed = ErasPendingFeeRewards::<T>::mutate(offence_era, validator, |fees| {
+		let value = slash * *fees;
+		*fees -= value;
+		value
+	});
+	ErasFeeRewardSlash::<T>::insert(offence_era, validator, fraction);
+
+	// The rewards are paid by minting, so the slashed part is minted to be handed to `T::Slash`.
+	T::Currency::issue(slashed)
+}
+
+/// Apply a previously-unapplied slash of an offence committed in `offence_era`.
+///
+/// The unlocking chunks of the stakers are slashed as of `slash_era`.
 pub(crate) fn apply_slash<T: Config>(
 	unapplied_slash: UnappliedSlash<T::AccountId, BalanceOf<T>>,
 	slash_era: EraIndex,
+	offence_era: EraIndex,
 ) {
-	let mut slashed_imbalance = NegativeImbalanceOf::<T>::zero();
+	let mut slashed_imbalance = slash_fee_rewards::<T>(&unapplied_slash.validator, offence_era);
 	let mut reward_payout = unapplied_slash.payout;
 
 	do_slash::<T>(
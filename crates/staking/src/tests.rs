@@ -50,7 +50,9 @@ fn set_staking_configs_works() {
 			ConfigOp::Set(10),
 			ConfigOp::Set(20),
 			ConfigOp::Set(Percent::from_percent(75)),
-			ConfigOp::Set(Zero::zero())
+			ConfigOp::Set(Zero::zero()),
+			ConfigOp::Set(Perbill::from_percent(50)),
+			ConfigOp::Set(Perbill::from_percent(5))
 		));
 		assert_eq!(MinNominatorBond::<Test>::get(), 1_500);
 		assert_eq!(MinValidatorBond::<Test>::get(), 2_000);
@@ -58,6 +60,8 @@ fn set_staking_configs_works() {
 		assert_eq!(MaxValidatorsCount::<Test>::get(), Some(20));
 		assert_eq!(ChillThreshold::<Test>::get(), Some(Percent::from_percent(75)));
 		assert_eq!(MinCommission::<Test>::get(), Perbill::from_percent(0));
+		assert_eq!(MaxCommission::<Test>::get(), Some(Perbill::from_percent(50)));
+		assert_eq!(MaxCommissionChangePerEra::<Test>::get(), Some(Perbill::from_percent(5)));
 
 		// noop does nothing
 		assert_storage_noop!(assert_ok!(Staking::set_staking_configs(
@@ -67,6 +71,8 @@ fn set_staking_configs_works() {
 			ConfigOp::Noop,
 			ConfigOp::Noop,
 			ConfigOp::Noop,
+			ConfigOp::Noop,
+			ConfigOp::Noop,
 			ConfigOp::Noop
 		)));
 
@@ -78,6 +84,8 @@ fn set_staking_configs_works() {
 			ConfigOp::Remove,
 			ConfigOp::Remove,
 			ConfigOp::Remove,
+			ConfigOp::Remove,
+			ConfigOp::Remove,
 			ConfigOp::Remove
 		));
 		assert_eq!(MinNominatorBond::<Test>::get(), 0);
@@ -86,6 +94,8 @@ fn set_staking_configs_works() {
 		assert_eq!(MaxValidatorsCount::<Test>::get(), None);
 		assert_eq!(ChillThreshold::<Test>::get(), None);
 		assert_eq!(MinCommission::<Test>::get(), Perbill::from_percent(0));
+		assert_eq!(MaxCommission::<Test>::get(), None);
+		assert_eq!(MaxCommissionChangePerEra::<Test>::get(), None);
 	});
 }
 
@@ -135,6 +145,137 @@ fn kill_stash_works() {
 	});
 }
 
+#[test]
+fn move_account_works() {
+	ExtBuilder::default().build_and_execute(|| {
+		// Account 11 is stashed and locked, and account 10 is the controller
+		assert_eq!(Staking::bonded(&11), Some(10));
+		assert!(Validators::<Test>::contains_key(&11));
+		let prefs = Staking::validators(&11);
+		assert_ok!(Staking::set_payee(RuntimeOrigin::signed(10), RewardDestination::Account(11)));
+
+		// Moving requires the admin origin.
+		assert_noop!(Staking::move_account(RuntimeOrigin::signed(11), 11, 99, 0, 1_000), BadOrigin);
+		// Not to itself, nor to an account that is staking.
+		assert_noop!(
+			Staking::move_account(RuntimeOrigin::root(), 11, 11, 0, 1_000),
+			Error::<Test>::SameAccount
+		);
+		assert_noop!(
+			Staking::move_account(RuntimeOrigin::root(), 11, 21, 0, 1_000),
+			Error::<Test>::AlreadyBonded
+		);
+		assert_noop!(
+			Staking::move_account(RuntimeOrigin::root(), 11, 20, 0, 1_000),
+			Error::<Test>::AlreadyPaired
+		);
+		// A controller is moved with its stash.
+		assert_noop!(
+			Staking::move_account(RuntimeOrigin::root(), 10, 99, 0, 1_000),
+			Error::<Test>::NotStash
+		);
+		// The exposures of 11 in history cannot be re-keyed.
+		assert_noop!(
+			Staking::move_account(RuntimeOrigin::root(), 11, 99, 0, 1_000),
+			Error::<Test>::ExposedInHistory
+		);
+		// Every exposure page in history is read to tell.
+		assert_noop!(
+			Staking::move_account(RuntimeOrigin::root(), 31, 99, 0, 0),
+			Error::<Test>::IncorrectExposurePages
+		);
+
+		// 11 validates again once its last exposure left history.
+		assert_ok!(Staking::chill(RuntimeOrigin::signed(10)));
+		mock::start_active_era(active_era() + HistoryDepth::get() + 1);
+		assert_ok!(Staking::validate(RuntimeOrigin::signed(10), prefs.clone()));
+		assert!(!Staking::is_exposed_in_history(&11, 1_000).unwrap());
+		let ledger = Staking::ledger(&10).unwrap();
+		let balance = Balances::free_balance(&11);
+		let _ = staking_events_since_last_call();
+
+		let issuance = Balances::total_issuance();
+		assert_ok!(Staking::move_account(RuntimeOrigin::root(), 11, 99, 0, 1_000));
+
+		// The stake of 11 now belongs to 99, which controls itself.
+		assert_eq!(Staking::bonded(&11), None);
+		assert_eq!(Staking::ledger(&10), None);
+		assert_eq!(Staking::bonded(&99), Some(99));
+		assert_eq!(Staking::ledger(&99), Some(StakingLedger { stash: 99, ..ledger }));
+		assert!(!Validators::<Test>::contains_key(&11));
+		assert_eq!(Staking::validators(&99), prefs);
+		assert!(<Test as Config>::VoterList::contains(&99));
+		assert_eq!(Staking::payee(&99), RewardDestination::Account(99));
+
+		// 11 is reaped, its whole balance went to 99, where it is locked.
+		assert_eq!(Balances::total_balance(&11), 0);
+		assert_eq!(Balances::free_balance(&99), balance);
+		assert_eq!(Balances::locks(&99)[0].amount, ledger.total);
+		assert_eq!(Balances::total_issuance(), issuance);
+		assert_eq!(
+			staking_events_since_last_call(),
+			vec![Event::AccountMoved { from: 11, to: 99, amount: balance }]
+		);
+
+		// An account that is not staking is simply emptied.
+		assert_ok!(Staking::move_account(RuntimeOrigin::signed(1), 1, 98, 0, 1_000));
+		assert_eq!(Balances::total_balance(&1), 0);
+		assert_eq!(Staking::bonded(&98), None);
+	});
+}
+
+#[test]
+fn move_account_moves_nominations() {
+	ExtBuilder::default().nominate(true).build_and_execute(|| {
+		let nominations = Staking::nominators(&101).unwrap();
+
+		// 101 backs 11 and 21 in the active era.
+		assert_noop!(
+			Staking::move_account(RuntimeOrigin::root(), 101, 98, 0, 1_000),
+			Error::<Test>::ExposedInHistory
+		);
+
+		assert_ok!(Staking::chill(RuntimeOrigin::signed(100)));
+		mock::start_active_era(active_era() + HistoryDepth::get() + 1);
+		assert_ok!(Staking::nominate(RuntimeOrigin::signed(100), nominations.targets.to_vec()));
+		let nominations = Staking::nominators(&101).unwrap();
+		let balance = Balances::free_balance(&101);
+
+		assert_ok!(Staking::move_account(RuntimeOrigin::root(), 101, 98, 0, 1_000));
+
+		assert_eq!(Staking::nominators(&101), None);
+		assert_eq!(Staking::nominators(&98), Some(nominations));
+		assert_eq!(Staking::ledger(&98).unwrap().active, 500);
+		assert_eq!(Balances::free_balance(&98), balance);
+	});
+}
+
+#[test]
+fn move_account_fails_with_pending_slash() {
+	ExtBuilder::default().build_and_execute(|| {
+		UnappliedSlashes::<Test>::insert(
+			active_era() + 1,
+			vec![UnappliedSlash {
+				validator: 21,
+				own: 10,
+				others: vec![(11, 10)],
+				reporters: vec![],
+				payout: 0,
+			}],
+		);
+
+		assert_noop!(
+			Staking::move_account(RuntimeOrigin::root(), 11, 99, 0, 1_000),
+			Error::<Test>::PendingSlash
+		);
+		assert_noop!(
+			Staking::move_account(RuntimeOrigin::root(), 21, 99, 0, 1_000),
+			Error::<Test>::PendingSlash
+		);
+		assert_ok!(Staking::move_account(RuntimeOrigin::root(), 31, 99, 0, 1_000));
+	});
+}
+
 #[test]
 fn basic_setup_works() {
 	// Verifies initial conditions of mock
@@ -496,8 +637,8 @@ fn less_than_needed_candidates_works() {
 
 			// But the exposure is updated in a simple way. No external votes exists.
 			// This is purely self-vote.
-			assert!(ErasStakers::<Test>::iter_prefix_values(active_era())
-				.all(|exposure| exposure.others.is_empty()));
+			assert!(ErasStakersOverview::<Test>::iter_prefix_values(active_era())
+				.all(|overview| overview.nominator_count == 0));
 		});
 }
 
@@ -616,7 +757,7 @@ fn nominating_and_rewards_should_work() {
 			assert_eq!(Balances::total_balance(&20), initial_balance_20 + total_payout_0 / 2);
 			initial_balance_20 = Balances::total_balance(&20);
 
-			assert_eq!(ErasStakers::<Test>::iter_prefix_values(active_era()).count(), 2);
+			assert_eq!(ErasStakersOverview::<Test>::iter_prefix_values(active_era()).count(), 2);
 			assert_eq!(
 				Staking::eras_stakers(active_era(), 11),
 				Exposure {
@@ -1725,7 +1866,7 @@ fn reward_to_stake_works() {
 			let _ = Balances::make_free_balance_be(&20, 1000);
 
 			// Bypass logic and change current exposure
-			ErasStakers::<Test>::insert(0, 21, Exposure { total: 69, own: 69, others: vec![] });
+			Staking::set_exposure(0, &21, Exposure { total: 69, own: 69, others: vec![] });
 			<Ledger<Test>>::insert(
 				&20,
 				StakingLedger {
@@ -2191,8 +2332,7 @@ fn reward_validator_slashing_validator_does_not_overflow() {
 
 		// Check reward
 		ErasRewardPoints::<Test>::insert(0, reward);
-		ErasStakers::<Test>::insert(0, 11, &exposure);
-		ErasStakersClipped::<Test>::insert(0, 11, exposure);
+		Staking::set_exposure(0, &11, exposure);
 		ErasValidatorReward::<Test>::insert(0, stake);
 		assert_ok!(Staking::payout_stakers(RuntimeOrigin::signed(1337), 11, 0));
 		assert_eq!(Balances::total_balance(&11), stake * 2);
@@ -2205,9 +2345,9 @@ fn reward_validator_slashing_validator_does_not_overflow() {
 		Staking::bond(RuntimeOrigin::signed(2), 20000, stake - 1, RewardDestination::default())
 			.unwrap();
 		// Override exposure of 11
-		ErasStakers::<Test>::insert(
+		Staking::set_exposure(
 			0,
-			11,
+			&11,
 			Exposure {
 				total: stake,
 				own: 1,
@@ -2251,6 +2391,26 @@ fn reward_from_authorship_event_handler_works() {
 	})
 }
 
+#[test]
+fn author_is_found_by_stash_after_controller_rotation() {
+	ExtBuilder::default().build_and_execute(|| {
+		// 11 is controlled by 12 from now on.
+		assert_ok!(Staking::set_controller(RuntimeOrigin::signed(11), 12));
+		assert_eq!(Staking::bonded(&11), Some(12));
+		let index = Session::validators().iter().position(|v| *v == 11).unwrap() as u32;
+
+		// The author is found from a BABE secondary plain pre-digest: the variant index, then
+		// the authority index and the slot.
+		System::deposit_log(sp_runtime::DigestItem::PreRuntime(
+			*b"BABE",
+			(2u8, index, 0u64).encode(),
+		));
+
+		let overview = Staking::exposure_overview(active_era(), &11).unwrap();
+		assert_eq!(<Staking as NominatorsHandle<Test>>::author(), Some((11, overview)));
+	})
+}
+
 #[test]
 fn add_reward_points_fns_works() {
 	ExtBuilder::default().build_and_execute(|| {
@@ -3679,9 +3839,9 @@ fn six_session_delay() {
 }
 
 #[test]
-fn test_max_nominator_rewarded_per_validator_and_cant_steal_someone_else_reward() {
+fn test_nominators_beyond_exposure_page_size_are_rewarded() {
 	ExtBuilder::default().build_and_execute(|| {
-		for i in 0..=<<Test as Config>::MaxNominatorRewardedPerValidator as Get<_>>::get() {
+		for i in 0..=<<Test as Config>::MaxExposurePageSize as Get<_>>::get() {
 			let stash = 10_000 + i as AccountId;
 			let controller = 20_000 + i as AccountId;
 			let balance = 10_000 + i as Balance;
@@ -3701,25 +3861,109 @@ fn test_max_nominator_rewarded_per_validator_and_cant_steal_someone_else_reward(
 		let _ = current_total_payout_for_duration(reward_time_per_era());
 
 		mock::start_active_era(2);
+		// The smallest nominator, 10_000, does not fit in the first page.
+		assert_eq!(Staking::exposure_overview(1, &11).unwrap().page_count, 2);
 		mock::make_all_reward_payment(1);
 
-		// Assert only nominators from 1 to Max are rewarded
-		for i in 0..=<<Test as Config>::MaxNominatorRewardedPerValidator as Get<_>>::get() {
+		// Assert all nominators are rewarded, whatever their page.
+		for i in 0..=<<Test as Config>::MaxExposurePageSize as Get<_>>::get() {
 			let stash = 10_000 + i as AccountId;
 			let balance = 10_000 + i as Balance;
-			if stash == 10_000 {
-				assert!(Balances::free_balance(&stash) == balance);
-			} else {
-				assert!(Balances::free_balance(&stash) > balance);
-			}
+			assert!(Balances::free_balance(&stash) > balance);
 		}
 	});
 }
 
+#[test]
+fn exposure_into_pages_works() {
+	let exposure = Exposure::<AccountId, Balance> {
+		total: 1_000 + 10 + 30 + 20,
+		own: 1_000,
+		others: vec![
+			IndividualExposure { who: 1, value: 10 },
+			IndividualExposure { who: 3, value: 30 },
+			IndividualExposure { who: 2, value: 20 },
+		],
+	};
+
+	// Nominators are sorted by stake, and only the first page includes the validator's own.
+	let (overview, pages) = exposure.clone().into_pages(2);
+	assert_eq!(
+		overview,
+		PagedExposureMetadata { total: 1_060, own: 1_000, nominator_count: 3, page_count: 2 }
+	);
+	assert_eq!(
+		pages,
+		vec![
+			ExposurePage {
+				page_total: 1_050,
+				others: vec![
+					IndividualExposure { who: 3, value: 30 },
+					IndividualExposure { who: 2, value: 20 },
+				],
+			},
+			ExposurePage { page_total: 10, others: vec![IndividualExposure { who: 1, value: 10 }] },
+		]
+	);
+
+	// A validator without nominators still has a page.
+	let (overview, pages) = Exposure::<AccountId, Balance> { total: 5, own: 5, others: vec![] }
+		.into_pages(2);
+	assert_eq!(overview.page_count, 1);
+	assert_eq!(pages, vec![ExposurePage { page_total: 5, others: vec![] }]);
+
+	// Storing the pages gives back the exposure.
+	ExtBuilder::default().build_and_execute(|| {
+		Staking::set_exposure(5, &11, exposure.clone());
+		assert_eq!(ErasStakersPaged::<Test>::iter_prefix((5, 11)).count(), 1);
+		let mut stored = Staking::eras_stakers(5, 11);
+		stored.others.sort_by_key(|individual| individual.who);
+		let mut expected = exposure;
+		expected.others.sort_by_key(|individual| individual.who);
+		assert_eq!(stored, expected);
+	});
+}
+
+#[test]
+fn is_exposed_in_era_checks_every_page_and_legacy_exposures() {
+	use sp_staking::StakingInterface;
+
+	ExtBuilder::default().build_and_execute(|| {
+		// 70 nominators do not fit in one page.
+		let others = (1_000..1_070)
+			.map(|who| IndividualExposure { who, value: who as Balance })
+			.collect::<Vec<_>>();
+		let total = others.iter().map(|i| i.value).sum::<Balance>();
+		Staking::set_exposure(5, &11, Exposure { total, own: 0, others });
+		assert_eq!(Staking::exposure_page_count(5, &11), 2);
+
+		assert!(<Staking as StakingInterface>::is_exposed_in_era(&11, &5));
+		assert!(<Staking as StakingInterface>::is_exposed_in_era(&1_069, &5));
+		// the smallest nominator is in the last page.
+		assert!(<Staking as StakingInterface>::is_exposed_in_era(&1_000, &5));
+		assert!(!<Staking as StakingInterface>::is_exposed_in_era(&1_070, &5));
+		assert!(!<Staking as StakingInterface>::is_exposed_in_era(&1_000, &6));
+
+		// exposures stored before paging are still checked.
+		ErasStakers::<Test>::insert(
+			6,
+			21,
+			Exposure {
+				total: 10,
+				own: 0,
+				others: vec![IndividualExposure { who: 1_000, value: 10 }],
+			},
+		);
+		assert!(<Staking as StakingInterface>::is_exposed_in_era(&21, &6));
+		assert!(<Staking as StakingInterface>::is_exposed_in_era(&1_000, &6));
+	});
+}
+
 #[test]
 fn test_payout_stakers() {
-	// Test that payout_stakers work in general, including that only the top
-	// `T::MaxNominatorRewardedPerValidator` nominators are rewarded.
+	// Test that payout_stakers work in general, including that the top
+	// `T::MaxExposurePageSize` nominators are rewarded by the first page, and the others by
+	// the next one.
 	ExtBuilder::default().has_stakers(false).build_and_execute(|| {
 		let balance = 1000;
 		// Track the exposure of the validator and all nominators.
@@ -3770,6 +4014,13 @@ fn test_payout_stakers() {
 		for i in 0..36 {
 			assert_eq!(Balances::free_balance(&(100 + i)), balance + i as Balance);
 		}
+		// and the era is not claimed until they are, by the second page.
+		assert_eq!(Staking::ledger(&10).unwrap().claimed_rewards, bounded_vec![]);
+		assert_eq!(Staking::exposure_overview(1, &11).unwrap().page_count, 2);
+		assert_ok!(Staking::payout_stakers(RuntimeOrigin::signed(1337), 11, 1));
+		for i in 0..36 {
+			assert!(Balances::free_balance(&(100 + i)) > balance + i as Balance);
+		}
 
 		// We track rewards in `claimed_rewards` vec
 		assert_eq!(
@@ -3800,6 +4051,7 @@ fn test_payout_stakers() {
 				1
 			);
 			assert!(RewardOnUnbalanceWasCalled::get());
+			assert_ok!(Staking::payout_stakers(RuntimeOrigin::signed(1337), 11, i - 1));
 		}
 
 		// We track rewards in `claimed_rewards` vec
@@ -3826,16 +4078,18 @@ fn test_payout_stakers() {
 		}
 
 		// We clean it up as history passes
-		assert_ok!(Staking::payout_stakers(
-			RuntimeOrigin::signed(1337),
-			11,
-			expected_start_reward_era
-		));
-		assert_ok!(Staking::payout_stakers(
-			RuntimeOrigin::signed(1337),
-			11,
-			expected_last_reward_era
-		));
+		for _ in 0..2 {
+			assert_ok!(Staking::payout_stakers(
+				RuntimeOrigin::signed(1337),
+				11,
+				expected_start_reward_era
+			));
+			assert_ok!(Staking::payout_stakers(
+				RuntimeOrigin::signed(1337),
+				11,
+				expected_last_reward_era
+			));
+		}
 		assert_eq!(
 			Staking::ledger(&10),
 			Some(StakingLedger {
@@ -3847,7 +4101,10 @@ fn test_payout_stakers() {
 			})
 		);
 
-		// Out of order claims works.
+		// Out of order claims works, for eras as well as pages.
+		assert_ok!(Staking::payout_stakers_by_page(RuntimeOrigin::signed(1337), 11, 69, 1));
+		assert_ok!(Staking::payout_stakers(RuntimeOrigin::signed(1337), 11, 23));
+		assert_ok!(Staking::payout_stakers(RuntimeOrigin::signed(1337), 11, 42));
 		assert_ok!(Staking::payout_stakers(RuntimeOrigin::signed(1337), 11, 69));
 		assert_ok!(Staking::payout_stakers(RuntimeOrigin::signed(1337), 11, 23));
 		assert_ok!(Staking::payout_stakers(RuntimeOrigin::signed(1337), 11, 42));
@@ -3928,6 +4185,37 @@ fn payout_stakers_handles_basic_errors() {
 			Staking::payout_stakers(RuntimeOrigin::signed(1337), 11, expected_last_reward_era + 1),
 			Error::<Test>::InvalidEraToReward.with_weight(err_weight)
 		);
+		// Wrong page, the 100 nominators fit in two pages.
+		assert_noop!(
+			Staking::payout_stakers_by_page(
+				RuntimeOrigin::signed(1337),
+				11,
+				expected_start_reward_era,
+				2
+			),
+			Error::<Test>::InvalidPage.with_weight(err_weight)
+		);
+		assert_ok!(Staking::payout_stakers(
+			RuntimeOrigin::signed(1337),
+			11,
+			expected_start_reward_era
+		));
+		assert_ok!(Staking::payout_stakers(
+			RuntimeOrigin::signed(1337),
+			11,
+			expected_last_reward_era
+		));
+
+		// Can't claim a page again
+		assert_noop!(
+			Staking::payout_stakers_by_page(
+				RuntimeOrigin::signed(1337),
+				11,
+				expected_start_reward_era,
+				0
+			),
+			Error::<Test>::AlreadyClaimed.with_weight(err_weight)
+		);
 		assert_ok!(Staking::payout_stakers(
 			RuntimeOrigin::signed(1337),
 			11,
@@ -3957,7 +4245,7 @@ fn payout_stakers_handles_weight_refund() {
 	// `payout_stakers` to calculate the weight of each payout op.
 	ExtBuilder::default().has_stakers(false).build_and_execute(|| {
 		let max_nom_rewarded =
-			<<Test as Config>::MaxNominatorRewardedPerValidator as Get<_>>::get();
+			<<Test as Config>::MaxExposurePageSize as Get<_>>::get();
 		// Make sure the configured value is meaningful for our use.
 		assert!(max_nom_rewarded >= 4);
 		let half_max_nom_rewarded = max_nom_rewarded / 2;
@@ -4820,7 +5108,9 @@ fn chill_other_works() {
 				ConfigOp::Remove,
 				ConfigOp::Remove,
 				ConfigOp::Remove,
-				ConfigOp::Remove
+				ConfigOp::Remove,
+				ConfigOp::Noop,
+				ConfigOp::Noop
 			));
 
 			// Still can't chill these users
@@ -4841,6 +5131,8 @@ fn chill_other_works() {
 				ConfigOp::Set(10),
 				ConfigOp::Set(10),
 				ConfigOp::Noop,
+				ConfigOp::Noop,
+				ConfigOp::Noop,
 				ConfigOp::Noop
 			));
 
@@ -4862,6 +5154,8 @@ fn chill_other_works() {
 				ConfigOp::Remove,
 				ConfigOp::Remove,
 				ConfigOp::Noop,
+				ConfigOp::Noop,
+				ConfigOp::Noop,
 				ConfigOp::Noop
 			));
 
@@ -4883,6 +5177,8 @@ fn chill_other_works() {
 				ConfigOp::Set(10),
 				ConfigOp::Set(10),
 				ConfigOp::Set(Percent::from_percent(75)),
+				ConfigOp::Noop,
+				ConfigOp::Noop,
 				ConfigOp::Noop
 			));
 
@@ -4929,6 +5225,8 @@ fn capped_stakers_works() {
 			ConfigOp::Set(max),
 			ConfigOp::Remove,
 			ConfigOp::Remove,
+			ConfigOp::Noop,
+			ConfigOp::Noop,
 		));
 
 		// can create `max - validator_count` validators
@@ -5002,6 +5300,8 @@ fn capped_stakers_works() {
 			ConfigOp::Remove,
 			ConfigOp::Noop,
 			ConfigOp::Noop,
+			ConfigOp::Noop,
+			ConfigOp::Noop,
 		));
 		assert_ok!(Staking::nominate(RuntimeOrigin::signed(last_nominator), vec![1]));
 		assert_ok!(Staking::validate(
@@ -5037,6 +5337,8 @@ fn min_commission_works() {
 			ConfigOp::Remove,
 			ConfigOp::Remove,
 			ConfigOp::Set(Perbill::from_percent(10)),
+			ConfigOp::Noop,
+			ConfigOp::Noop,
 		));
 
 		// can't make it less than 10 now
@@ -5061,6 +5363,165 @@ fn min_commission_works() {
 	})
 }
 
+#[test]
+fn max_commission_works() {
+	let prefs = |c| ValidatorPrefs { commission: Perbill::from_percent(c), blocked: false };
+	ExtBuilder::default().build_and_execute(|| {
+		assert_ok!(Staking::set_staking_configs(
+			RuntimeOrigin::root(),
+			ConfigOp::Noop,
+			ConfigOp::Noop,
+			ConfigOp::Noop,
+			ConfigOp::Noop,
+			ConfigOp::Noop,
+			ConfigOp::Set(Perbill::from_percent(10)),
+			ConfigOp::Set(Perbill::from_percent(20)),
+			ConfigOp::Noop,
+		));
+
+		// can't make it more than 20 now
+		assert_noop!(
+			Staking::validate(RuntimeOrigin::signed(10), prefs(25)),
+			Error::<Test>::CommissionTooHigh
+		);
+		assert_ok!(Staking::validate(RuntimeOrigin::signed(10), prefs(20)));
+
+		// the minimum can't exceed the maximum, nor the maximum go below the minimum
+		assert_noop!(
+			Staking::set_min_commission(RuntimeOrigin::root(), Perbill::from_percent(25)),
+			Error::<Test>::InvalidCommissionBounds
+		);
+		assert_noop!(
+			Staking::set_staking_configs(
+				RuntimeOrigin::root(),
+				ConfigOp::Noop,
+				ConfigOp::Noop,
+				ConfigOp::Noop,
+				ConfigOp::Noop,
+				ConfigOp::Noop,
+				ConfigOp::Noop,
+				ConfigOp::Set(Perbill::from_percent(5)),
+				ConfigOp::Noop,
+			),
+			Error::<Test>::InvalidCommissionBounds
+		);
+
+		// removing the maximum lifts the cap
+		assert_ok!(Staking::set_staking_configs(
+			RuntimeOrigin::root(),
+			ConfigOp::Noop,
+			ConfigOp::Noop,
+			ConfigOp::Noop,
+			ConfigOp::Noop,
+			ConfigOp::Noop,
+			ConfigOp::Noop,
+			ConfigOp::Remove,
+			ConfigOp::Noop,
+		));
+		assert_ok!(Staking::validate(RuntimeOrigin::signed(10), prefs(100)));
+	})
+}
+
+#[test]
+fn max_commission_change_per_era_works() {
+	let prefs = |c| ValidatorPrefs { commission: Perbill::from_percent(c), blocked: false };
+	ExtBuilder::default().build_and_execute(|| {
+		assert_ok!(Staking::set_staking_configs(
+			RuntimeOrigin::root(),
+			ConfigOp::Noop,
+			ConfigOp::Noop,
+			ConfigOp::Noop,
+			ConfigOp::Noop,
+			ConfigOp::Noop,
+			ConfigOp::Noop,
+			ConfigOp::Noop,
+			ConfigOp::Set(Perbill::from_percent(5)),
+		));
+		assert_eq!(Validators::<Test>::get(11), prefs(0));
+		let _ = staking_events_since_last_call();
+
+		// can't raise it by more than 5 in one go
+		assert_noop!(
+			Staking::validate(RuntimeOrigin::signed(10), prefs(6)),
+			Error::<Test>::CommissionChangeTooHigh
+		);
+		assert_ok!(Staking::validate(RuntimeOrigin::signed(10), prefs(3)));
+		assert_eq!(
+			staking_events_since_last_call(),
+			vec![
+				Event::ValidatorCommissionChanged {
+					stash: 11,
+					old: Perbill::zero(),
+					new: Perbill::from_percent(3)
+				},
+				Event::ValidatorPrefsSet { stash: 11, prefs: prefs(3) },
+			]
+		);
+
+		// nor by more than 5 in one era, in several steps
+		assert_noop!(
+			Staking::validate(RuntimeOrigin::signed(10), prefs(6)),
+			Error::<Test>::CommissionChangeTooHigh
+		);
+		assert_ok!(Staking::validate(RuntimeOrigin::signed(10), prefs(5)));
+		assert_eq!(CommissionAtEraStart::<Test>::get(11), Some((0, Perbill::zero())));
+
+		// lowering it is always possible, and does not reset the limit of the era
+		assert_ok!(Staking::validate(RuntimeOrigin::signed(10), prefs(0)));
+		assert_ok!(Staking::validate(RuntimeOrigin::signed(10), prefs(5)));
+		assert_noop!(
+			Staking::validate(RuntimeOrigin::signed(10), prefs(6)),
+			Error::<Test>::CommissionChangeTooHigh
+		);
+
+		// setting the same commission again emits no change
+		let _ = staking_events_since_last_call();
+		assert_ok!(Staking::validate(RuntimeOrigin::signed(10), prefs(5)));
+		assert_eq!(
+			staking_events_since_last_call(),
+			vec![Event::ValidatorPrefsSet { stash: 11, prefs: prefs(5) }]
+		);
+
+		// in the next era it can be raised by 5 again
+		mock::start_active_era(1);
+		assert_noop!(
+			Staking::validate(RuntimeOrigin::signed(10), prefs(11)),
+			Error::<Test>::CommissionChangeTooHigh
+		);
+		assert_ok!(Staking::validate(RuntimeOrigin::signed(10), prefs(10)));
+		assert_eq!(CommissionAtEraStart::<Test>::get(11), Some((1, Perbill::from_percent(5))));
+	})
+}
+
+#[test]
+fn max_commission_change_per_era_survives_chill() {
+	let prefs = |c| ValidatorPrefs { commission: Perbill::from_percent(c), blocked: false };
+	ExtBuilder::default().build_and_execute(|| {
+		MaxCommissionChangePerEra::<Test>::put(Perbill::from_percent(5));
+		assert_ok!(Staking::validate(RuntimeOrigin::signed(10), prefs(5)));
+
+		// chilling and validating again does not reset the limit of the era
+		assert_ok!(Staking::chill(RuntimeOrigin::signed(10)));
+		assert_noop!(
+			Staking::validate(RuntimeOrigin::signed(10), prefs(100)),
+			Error::<Test>::CommissionChangeTooHigh
+		);
+		assert_ok!(Staking::validate(RuntimeOrigin::signed(10), prefs(5)));
+
+		// nor once the eras it was elected in passed, the last commission being kept
+		assert_ok!(Staking::chill(RuntimeOrigin::signed(10)));
+		mock::start_active_era(active_era() + 2);
+		assert!(!ErasValidatorPrefs::<Test>::contains_key(active_era(), 11));
+		assert_eq!(ChilledCommission::<Test>::get(11), Some(Perbill::from_percent(5)));
+		assert_noop!(
+			Staking::validate(RuntimeOrigin::signed(10), prefs(100)),
+			Error::<Test>::CommissionChangeTooHigh
+		);
+		assert_ok!(Staking::validate(RuntimeOrigin::signed(10), prefs(10)));
+		assert_eq!(ChilledCommission::<Test>::get(11), None);
+	})
+}
+
 #[test]
 fn change_of_max_nominations() {
 	use frame_election_provider_support::ElectionDataProvider;
@@ -5232,6 +5693,14 @@ fn force_apply_min_commission_works() {
 		assert_ok!(Staking::force_apply_min_commission(RuntimeOrigin::signed(1), 11));
 		// Then the commission is bumped to the min
 		assert_eq!(validators(), vec![(31, prefs(10)), (21, prefs(5)), (11, prefs(5))]);
+		assert_eq!(
+			*staking_events().last().unwrap(),
+			Event::ValidatorCommissionChanged {
+				stash: 11,
+				old: Perbill::zero(),
+				new: Perbill::from_percent(5)
+			}
+		);
 
 		// When applying commission to a validator that doesn't exist then storage is not altered
 		assert_noop!(
@@ -5629,6 +6098,408 @@ fn reducing_history_depth_abrupt() {
 	});
 }
 
+#[test]
+fn fee_rewards_are_paid_to_payee() {
+	ExtBuilder::default().build_and_execute(|| {
+		// 11 is stashed and controlled by 10
+		let balance = |who| Balances::total_balance(&who);
+		let (stash, controller, other) = (balance(11), balance(10), balance(42));
+		let active = Staking::ledger(&10).unwrap().active;
+
+		assert_ok!(Staking::set_payee(RuntimeOrigin::signed(10), RewardDestination::Controller));
+		assert_eq!(Staking::payout_fee_reward(&11, 1), 1);
+		assert_eq!(Balances::total_balance(&10), controller + 1);
+
+		assert_ok!(Staking::set_payee(RuntimeOrigin::signed(10), RewardDestination::Stash));
+		assert_eq!(Staking::payout_fee_reward(&11, 2), 2);
+		assert_eq!(Balances::total_balance(&11), stash + 2);
+		assert_eq!(Staking::ledger(&10).unwrap().active, active);
+
+		// compounds into the active bond
+		assert_ok!(Staking::set_payee(RuntimeOrigin::signed(10), RewardDestination::Staked));
+		assert_eq!(Staking::payout_fee_reward(&11, 4), 4);
+		assert_eq!(Balances::total_balance(&11), stash + 6);
+		assert_eq!(Staking::ledger(&10).unwrap().active, active + 4);
+		assert_eq!(Staking::ledger(&10).unwrap().total, active + 4);
+
+		assert_ok!(Staking::set_payee(RuntimeOrigin::signed(10), RewardDestination::Account(42)));
+		assert_eq!(Staking::payout_fee_reward(&11, 8), 8);
+		assert_eq!(Balances::total_balance(&42), other + 8);
+
+		assert_ok!(Staking::set_payee(RuntimeOrigin::signed(10), RewardDestination::None));
+		assert_eq!(Staking::payout_fee_reward(&11, 16), 0);
+
+		// not bonded
+		assert_eq!(Staking::payout_fee_reward(&10, 32), 0);
+
+		assert_eq!(Balances::total_balance(&10), controller + 1);
+		assert_eq!(Balances::total_balance(&11), stash + 6);
+		assert_eq!(Balances::total_balance(&42), other + 8);
+	});
+}
+
+#[test]
+fn fee_rewards_follow_controller_changes() {
+	ExtBuilder::default().build_and_execute(|| {
+		assert_ok!(Staking::set_payee(RuntimeOrigin::signed(10), RewardDestination::Controller));
+		let _ = Balances::make_free_balance_be(&5, 10);
+
+		// rotate the controller of 11 from 10 to 5
+		assert_ok!(Staking::set_controller(RuntimeOrigin::signed(11), 5));
+		assert_eq!(Staking::payout_fee_reward(&11, 1), 1);
+		assert_eq!(Balances::total_balance(&5), 11);
+
+		// stash is its own controller
+		assert_ok!(Staking::set_controller(RuntimeOrigin::signed(11), 11));
+		let active = Staking::ledger(&11).unwrap().active;
+		assert_ok!(Staking::set_payee(RuntimeOrigin::signed(11), RewardDestination::Staked));
+		assert_eq!(Staking::payout_fee_reward(&11, 2), 2);
+		assert_eq!(Staking::ledger(&11).unwrap().active, active + 2);
+	});
+}
+
+#[test]
+fn fee_rewards_are_pruned_with_era_history() {
+	ExtBuilder::default().build_and_execute(|| {
+		let history_depth = HistoryDepth::get();
+		ErasCurrentValidatorReward::<Test>::insert(1, 11, 100);
+		ErasCurrentValidatorReward::<Test>::insert(1, 21, 50);
+
+		mock::start_active_era(2);
+		ErasCurrentValidatorReward::<Test>::insert(2, 11, 30);
+		assert_eq!(Staking::api_eras_fee_rewards()[..3], [(0, 0), (1, 150), (2, 30)]);
+		assert_eq!(Staking::api_validator_fee_rewards(11)[..3], [(0, 0), (1, 100), (2, 30)]);
+
+		// era 1 is still kept
+		mock::start_active_era(history_depth + 1);
+		assert_eq!(Staking::eras_current_validator_reward(1, 11), Some(100));
+
+		// and cleared along with the rest of its era information
+		mock::start_active_era(history_depth + 2);
+		assert_eq!(Staking::eras_current_validator_reward(1, 11), None);
+		assert_eq!(Staking::eras_current_validator_reward(1, 21), None);
+		assert_eq!(Staking::eras_current_validator_reward(2, 11), Some(30));
+		assert_eq!(Staking::api_eras_fee_rewards()[0], (2, 30));
+	});
+}
+
+#[test]
+fn api_unapplied_slashes_reports_deferred_slashes() {
+	ExtBuilder::default().slash_defer_duration(2).build_and_execute(|| {
+		mock::start_active_era(1);
+		ErasCurrentValidatorReward::<Test>::insert(1, 11, 50);
+		assert!(Staking::api_unapplied_slashes().is_empty());
+
+		let exposure = Staking::eras_stakers(active_era(), 11);
+		let nominated_value = exposure.others.iter().find(|o| o.who == 101).unwrap().value;
+		on_offence_now(
+			&[OffenceDetails { offender: (11, exposure), reporters: vec![] }],
+			&[Perbill::from_percent(10)],
+		);
+
+		// the offence of era 1 is applied at the start of era 4.
+		let report = Staking::api_unapplied_slashes();
+		assert_eq!(report.len(), 1);
+		let (apply_era, slash_era, slashes) = &report[0];
+		assert_eq!((*apply_era, *slash_era), (4, 1));
+		assert_eq!(slashes.len(), 1);
+		let (slash, fee_rewards) = &slashes[0];
+		assert_eq!(slash.validator, 11);
+		assert_eq!(slash.own, 100);
+		assert_eq!(slash.others, vec![(101, nominated_value / 10)]);
+		assert_eq!(*fee_rewards, 50);
+
+		mock::start_active_era(3);
+		assert_eq!(Staking::api_unapplied_slashes().len(), 1);
+		mock::start_active_era(4);
+		assert!(Staking::api_unapplied_slashes().is_empty());
+	});
+}
+
+#[test]
+fn fee_rewards_are_paid_out_once_slashes_are_applied() {
+	ExtBuilder::default().slash_defer_duration(2).build_and_execute(|| {
+		let err_weight = <Test as Config>::WeightInfo::payout_fee_rewards(0);
+		assert_ok!(Staking::set_payee(RuntimeOrigin::signed(10), RewardDestination::Stash));
+		assert_ok!(Staking::set_payee(RuntimeOrigin::signed(100), RewardDestination::Stash));
+
+		mock::start_active_era(1);
+		assert_ok!(Staking::credit_fee_reward(&11, 40));
+		assert_ok!(Staking::credit_fee_reward(&11, 60));
+		assert_eq!(ErasPendingFeeRewards::<Test>::get(1, 11), 100);
+
+		// the slashes of era 1 are applied at the start of era 4.
+		mock::start_active_era(3);
+		assert_noop!(
+			Staking::payout_fee_rewards(RuntimeOrigin::signed(1337), 11, 1),
+			Error::<Test>::FeeRewardsLocked.with_weight(err_weight)
+		);
+
+		mock::start_active_era(4);
+		let (stash, nominator) = (Balances::total_balance(&11), Balances::total_balance(&101));
+		assert_ok!(Staking::payout_fee_rewards(RuntimeOrigin::signed(1337), 11, 1));
+		// a tenth for the validator, the rest for its only nominator.
+		assert_eq!(Balances::total_balance(&11), stash + 10);
+		assert_eq!(Balances::total_balance(&101), nominator + 90);
+		assert_eq!(ClaimedFeeRewards::<Test>::get(1, 11), vec![0]);
+
+		assert_noop!(
+			Staking::payout_fee_rewards(RuntimeOrigin::signed(1337), 11, 1),
+			Error::<Test>::NoFeeRewards.with_weight(err_weight)
+		);
+		assert_noop!(
+			Staking::payout_fee_rewards(RuntimeOrigin::signed(1337), 21, 1),
+			Error::<Test>::NoFeeRewards.with_weight(err_weight)
+		);
+		assert_noop!(
+			Staking::payout_fee_rewards(RuntimeOrigin::signed(1337), 11, 10),
+			Error::<Test>::InvalidEraToReward.with_weight(err_weight)
+		);
+	});
+}
+
+#[test]
+fn fee_rewards_are_paid_out_page_by_page() {
+	ExtBuilder::default().build_and_execute(|| {
+		for controller in [10, 20, 100] {
+			assert_ok!(Staking::set_payee(
+				RuntimeOrigin::signed(controller),
+				RewardDestination::Stash
+			));
+		}
+		mock::start_active_era(1);
+		let page = |who, value| ExposurePage {
+			page_total: value,
+			others: vec![IndividualExposure { who, value }],
+		};
+		ErasStakersOverview::<Test>::insert(
+			1,
+			11,
+			PagedExposureMetadata { total: 1_300, own: 1_000, nominator_count: 2, page_count: 2 },
+		);
+		ErasStakersPaged::<Test>::insert((1, 11, 0), page(101, 100));
+		ErasStakersPaged::<Test>::insert((1, 11, 1), page(21, 200));
+		assert_ok!(Staking::credit_fee_reward(&11, 1_000));
+
+		mock::start_active_era(2);
+		let balance = |who| Balances::total_balance(&who);
+		let balances = || (balance(11), balance(101), balance(21));
+		let (validator, first, second) = balances();
+		let _ = staking_events_since_last_call();
+
+		// the first page pays the validator along with the nominators of the page.
+		assert_ok!(Staking::payout_fee_rewards(RuntimeOrigin::signed(1337), 11, 1));
+		assert_eq!(balances(), (validator + 100, first + 300, second));
+		assert_eq!(
+			staking_events_since_last_call(),
+			vec![
+				Event::Rewarded { stash: 11, amount: 100 },
+				Event::Rewarded { stash: 101, amount: 300 },
+			]
+		);
+
+		assert_ok!(Staking::payout_fee_rewards(RuntimeOrigin::signed(1337), 11, 1));
+		assert_eq!(balances(), (validator + 100, first + 300, second + 600));
+		assert_eq!(ClaimedFeeRewards::<Test>::get(1, 11), vec![0, 1]);
+		assert_noop!(
+			Staking::payout_fee_rewards(RuntimeOrigin::signed(1337), 11, 1),
+			Error::<Test>::NoFeeRewards
+				.with_weight(<Test as Config>::WeightInfo::payout_fee_rewards(0))
+		);
+	});
+}
+
+#[test]
+fn fee_rewards_are_slashed_with_the_validator() {
+	ExtBuilder::default().build_and_execute(|| {
+		mock::start_active_era(1);
+		assert_ok!(Staking::credit_fee_reward(&11, 1000));
+		assert_ok!(Staking::credit_fee_reward(&21, 100));
+		let slashed = SlashUnbalanced::get();
+
+		let exposure = Staking::eras_stakers(active_era(), 11);
+		let nominated_value = exposure.others.iter().find(|o| o.who == 101).unwrap().value;
+		on_offence_now(
+			&[OffenceDetails { offender: (11, exposure.clone()), reporters: vec![] }],
+			&[Perbill::from_percent(10)],
+		);
+
+		// the fee rewards behind 11 are slashed by the same fraction as its stake, and sent
+		// along with it to `Slash`.
+		assert_eq!(ErasPendingFeeRewards::<Test>::get(1, 11), 900);
+		assert_eq!(ErasPendingFeeRewards::<Test>::get(1, 21), 100);
+		assert_eq!(SlashUnbalanced::get(), slashed + 100 + nominated_value / 10 + 100);
+
+		// a larger slash in the same era only slashes the rest of the rewards up to it.
+		on_offence_now(
+			&[OffenceDetails { offender: (11, exposure), reporters: vec![] }],
+			&[Perbill::from_percent(25)],
+		);
+		assert_eq!(ErasPendingFeeRewards::<Test>::get(1, 11), 750);
+		assert_eq!(ErasFeeRewardSlash::<Test>::get(1, 11), Perbill::from_percent(25));
+
+		mock::start_active_era(2);
+		let (stash, nominator) = (Balances::total_balance(&11), Balances::total_balance(&101));
+		assert_ok!(Staking::payout_fee_rewards(RuntimeOrigin::signed(1337), 11, 1));
+		assert_eq!(Balances::total_balance(&11), stash + 75);
+		assert_eq!(Balances::total_balance(&101), nominator + 675);
+	});
+}
+
+#[test]
+fn deferred_slashes_slash_fee_rewards_of_the_offence_era() {
+	ExtBuilder::default().slash_defer_duration(2).build_and_execute(|| {
+		mock::start_active_era(1);
+		assert_ok!(Staking::credit_fee_reward(&11, 100));
+		on_offence_now(
+			&[OffenceDetails {
+				offender: (11, Staking::eras_stakers(active_era(), 11)),
+				reporters: vec![],
+			}],
+			&[Perbill::from_percent(10)],
+		);
+
+		mock::start_active_era(2);
+		assert_ok!(Staking::credit_fee_reward(&11, 100));
+		mock::start_active_era(3);
+		assert_eq!(ErasPendingFeeRewards::<Test>::get(1, 11), 100);
+
+		// the slash is applied to the rewards of era 1 only.
+		mock::start_active_era(4);
+		assert_eq!(ErasPendingFeeRewards::<Test>::get(1, 11), 90);
+		assert_eq!(ErasPendingFeeRewards::<Test>::get(2, 11), 100);
+	});
+}
+
+#[test]
+fn deferred_slashes_slash_fee_rewards_after_the_slashing_metadata_is_pruned() {
+	ExtBuilder::default().bonding_duration(0).slash_defer_duration(2).build_and_execute(|| {
+		mock::start_active_era(1);
+		assert_ok!(Staking::credit_fee_reward(&11, 100));
+		on_offence_now(
+			&[OffenceDetails {
+				offender: (11, Staking::eras_stakers(active_era(), 11)),
+				reporters: vec![],
+			}],
+			&[Perbill::from_percent(10)],
+		);
+
+		// without bonding duration, the slashing metadata of era 1 is gone in era 2.
+		mock::start_active_era(2);
+		assert!(<Staking as crate::Store>::ValidatorSlashInEra::get(1, 11).is_none());
+		assert_eq!(ErasPendingFeeRewardSlash::<Test>::get(1, 11), Perbill::from_percent(10));
+
+		mock::start_active_era(4);
+		assert_eq!(ErasPendingFeeRewards::<Test>::get(1, 11), 90);
+		assert_eq!(ErasFeeRewardSlash::<Test>::get(1, 11), Perbill::from_percent(10));
+	});
+}
+
+#[test]
+fn cancelled_slashes_do_not_slash_fee_rewards() {
+	ExtBuilder::default().slash_defer_duration(2).build_and_execute(|| {
+		mock::start_active_era(1);
+		assert_ok!(Staking::credit_fee_reward(&11, 100));
+		on_offence_now(
+			&[OffenceDetails {
+				offender: (11, Staking::eras_stakers(active_era(), 11)),
+				reporters: vec![],
+			}],
+			&[Perbill::from_percent(10)],
+		);
+
+		mock::start_active_era(2);
+		assert_ok!(Staking::cancel_deferred_slash(RuntimeOrigin::root(), 4, vec![0]));
+
+		mock::start_active_era(4);
+		assert_eq!(ErasPendingFeeRewards::<Test>::get(1, 11), 100);
+		assert_eq!(ErasFeeRewardSlash::<Test>::get(1, 11), Perbill::zero());
+	});
+}
+
+#[test]
+fn pending_fee_rewards_are_pruned_with_era_history() {
+	ExtBuilder::default().build_and_execute(|| {
+		let history_depth = HistoryDepth::get();
+		mock::start_active_era(1);
+		assert_ok!(Staking::credit_fee_reward(&11, 100));
+		ErasFeeRewardSlash::<Test>::insert(1, 11, Perbill::from_percent(10));
+
+		mock::start_active_era(history_depth + 1);
+		assert_eq!(ErasPendingFeeRewards::<Test>::get(1, 11), 100);
+
+		mock::start_active_era(history_depth + 2);
+		assert_eq!(ErasPendingFeeRewards::<Test>::get(1, 11), 0);
+		assert_eq!(ErasFeeRewardSlash::<Test>::get(1, 11), Perbill::zero());
+	});
+}
+
+#[test]
+fn v14_migration_prunes_stale_fee_rewards() {
+	use frame_support::traits::OnRuntimeUpgrade;
+
+	ExtBuilder::default().build_and_execute(|| {
+		let history_depth = HistoryDepth::get();
+		mock::start_active_era(history_depth + 5);
+		let oldest_era = Staking::current_era().unwrap() - history_depth;
+
+		// entries left behind before `clear_era_information` pruned them.
+		ErasCurrentValidatorReward::<Test>::insert(oldest_era - 3, 11, 10);
+		ErasCurrentValidatorReward::<Test>::insert(oldest_era - 1, 21, 10);
+		ErasCurrentValidatorReward::<Test>::insert(oldest_era, 11, 20);
+		assert!(Staking::do_try_state(System::block_number()).is_err());
+		StorageVersion::new(13).put::<Staking>();
+
+		crate::migrations::v14::MigrateToV14::<Test>::on_runtime_upgrade();
+
+		assert_eq!(Staking::on_chain_storage_version(), 14);
+		assert_eq!(
+			ErasCurrentValidatorReward::<Test>::iter().collect::<Vec<_>>(),
+			vec![(oldest_era, 11, 20)]
+		);
+		assert_ok!(Staking::do_try_state(System::block_number()));
+	});
+}
+
+#[test]
+fn v15_migration_rekeys_slashes_to_the_new_defer_duration() {
+	use frame_support::traits::{ConstU32, OnRuntimeUpgrade};
+
+	ExtBuilder::default().slash_defer_duration(2).build_and_execute(|| {
+		mock::start_active_era(4);
+		let slash = |own| UnappliedSlash {
+			validator: 11,
+			own,
+			others: vec![(101, own / 2)],
+			reporters: vec![],
+			payout: 0,
+		};
+		// slashes of eras 1 and 3 deferred by 5 eras.
+		UnappliedSlashes::<Test>::insert(7, vec![slash(10)]);
+		UnappliedSlashes::<Test>::insert(9, vec![slash(20)]);
+		StorageVersion::new(14).put::<Staking>();
+		let (stash, nominator) = (Balances::total_balance(&11), Balances::total_balance(&101));
+
+		crate::migrations::v15::MigrateToV15::<Test, ConstU32<5>>::on_runtime_upgrade();
+
+		assert_eq!(Staking::on_chain_storage_version(), 15);
+		// the slash of era 1 is due since era 4 and applied right away.
+		assert_eq!(Balances::total_balance(&11), stash - 10);
+		assert_eq!(Balances::total_balance(&101), nominator - 5);
+		// the slash of era 3 is now applied at the start of era 6.
+		let pending = UnappliedSlashes::<Test>::iter()
+			.map(|(era, slashes)| (era, slashes.iter().map(|s| s.own).collect::<Vec<_>>()))
+			.collect::<Vec<_>>();
+		assert_eq!(pending, vec![(6, vec![20])]);
+		assert_eq!(Staking::api_unapplied_slashes()[0].1, 3);
+
+		mock::start_active_era(6);
+		assert_eq!(Balances::total_balance(&11), stash - 30);
+		assert_eq!(Balances::total_balance(&101), nominator - 15);
+		assert_eq!(UnappliedSlashes::<Test>::iter().count(), 0);
+	});
+}
+
 #[test]
 fn reducing_max_unlocking_chunks_abrupt() {
 	// Concern is on validators only
@@ -5775,6 +6646,114 @@ fn set_min_commission_works_with_admin_origin() {
 	})
 }
 
+#[test]
+fn inflation_curve_follows_params_set_by_admin_origin() {
+	frame_support::parameter_types! {
+		pub const DefaultParams: InflationParams = InflationParams {
+			min_inflation: Perbill::from_parts(25_000_000),
+			max_inflation: Perbill::from_percent(10),
+			ideal_stake: Perbill::from_percent(50),
+			falloff: Perbill::from_percent(5),
+		};
+	}
+	type Curve = InflationCurve<Test, DefaultParams>;
+	const YEAR: u64 = 365 * 24 * 60 * 60 * 1000;
+
+	ExtBuilder::default().build_and_execute(|| {
+		// the default parameters are used until some are set, and their curve is cached.
+		assert_eq!(Curve::params(), DefaultParams::get());
+		assert!(InflationCurvePoints::<Test>::get().is_empty());
+		let (payout, rest) = Curve::era_payout(50_000, 100_000, YEAR);
+		assert_eq_error_rate!(payout, 9_993, 1);
+		assert_eq_error_rate!(rest, 0, 1);
+		assert_eq!(InflationCurvePoints::<Test>::get().len(), 40);
+
+		let params = InflationParams {
+			min_inflation: Perbill::zero(),
+			max_inflation: Perbill::from_percent(20),
+			ideal_stake: Perbill::from_percent(50),
+			falloff: Perbill::from_percent(5),
+		};
+
+		// Non privileged origin can not set the parameters
+		assert_noop!(Staking::set_inflation_params(RuntimeOrigin::signed(2), params), BadOrigin);
+		// nor can the admin origin set an invalid curve.
+		assert_noop!(
+			Staking::set_inflation_params(
+				RuntimeOrigin::signed(1),
+				InflationParams { ideal_stake: Perbill::one(), ..params }
+			),
+			Error::<Test>::InvalidInflationParams
+		);
+		assert_noop!(
+			Staking::set_inflation_params(
+				RuntimeOrigin::signed(1),
+				InflationParams { min_inflation: Perbill::from_percent(21), ..params }
+			),
+			Error::<Test>::InvalidInflationParams
+		);
+
+		assert_ok!(Staking::set_inflation_params(RuntimeOrigin::signed(1), params));
+		assert_eq!(*staking_events().last().unwrap(), Event::InflationParamsSet { params });
+		assert_eq!(Curve::params(), params);
+		assert!(InflationCurvePoints::<Test>::get().is_empty());
+
+		// the new curve is used right away.
+		let (payout, rest) = Curve::era_payout(50_000, 100_000, YEAR);
+		assert_eq_error_rate!(payout, 19_986, 1);
+		assert_eq_error_rate!(rest, 0, 1);
+		let (payout, rest) = Curve::era_payout(0, 100_000, YEAR);
+		assert_eq!(payout, 0);
+		assert_eq_error_rate!(rest, 19_986, 1);
+	})
+}
+
+#[test]
+fn fee_aware_era_payout_offsets_inflation_by_fees() {
+	frame_support::parameter_types! {
+		pub const TargetYield: Perbill = Perbill::from_percent(10);
+	}
+	type Inner = <Test as Config>::EraPayout;
+	type Payout = FeeAwareEraPayout<Test, Inner, TargetYield>;
+	const YEAR: u64 = 365 * 24 * 60 * 60 * 1000;
+
+	ExtBuilder::default().build_and_execute(|| {
+		mock::start_active_era(1);
+		let era = active_era();
+		// At the ideal stake, the yield of inflation alone, 20% of the stake, exceeds the target.
+		let (payout, rest) = Inner::era_payout(50_000, 100_000, YEAR);
+		assert!(payout > inflation::era_portion_of_year(YEAR) * 5_000);
+
+		// without fees, the payout is the one of the inflation curve.
+		assert_eq!(Payout::era_payout(50_000, 100_000, YEAR), (payout, rest));
+
+		// fees reduce the inflation paid to stakers, the difference going to the rest.
+		ErasCurrentValidatorReward::<Test>::insert(era, 11, 300);
+		ErasCurrentValidatorReward::<Test>::insert(era, 21, 200);
+		assert_eq!(Payout::era_payout(50_000, 100_000, YEAR), (payout - 500, rest + 500));
+
+		// but never below zero.
+		ErasCurrentValidatorReward::<Test>::insert(era, 21, 100_000);
+		assert_eq!(Payout::era_payout(50_000, 100_000, YEAR), (0, rest + payout));
+
+		// Well beyond the ideal stake, the yield of inflation is less than the target, only the
+		// fees beyond it reduce inflation.
+		let (payout, rest) = Inner::era_payout(90_000, 100_000, YEAR);
+		let target = inflation::era_portion_of_year(YEAR) * 9_000;
+		assert!(payout < target);
+		ErasCurrentValidatorReward::<Test>::insert(era, 11, 0);
+		ErasCurrentValidatorReward::<Test>::insert(era, 21, target - payout);
+		assert_eq!(Payout::era_payout(90_000, 100_000, YEAR), (payout, rest));
+		ErasCurrentValidatorReward::<Test>::insert(era, 21, target - payout + 50);
+		assert_eq!(Payout::era_payout(90_000, 100_000, YEAR), (payout - 50, rest + 50));
+
+		// fees of other eras are ignored.
+		let _ = ErasCurrentValidatorReward::<Test>::clear_prefix(era, u32::MAX, None);
+		ErasCurrentValidatorReward::<Test>::insert(era - 1, 11, 1_000);
+		assert_eq!(Payout::era_payout(90_000, 100_000, YEAR), (payout, rest));
+	})
+}
+
 mod staking_interface {
 	use frame_support::storage::with_storage_layer;
 	use sp_staking::StakingInterface;
@@ -10,7 +10,10 @@ use sp_runtime::traits::{IdentifyAccount, Verify};
 use sp_state_machine::BasicExternalities;
 // Frontier
 use qchain_template_runtime::{AccountId, Balance, Block, EnableManualSeal, GenesisConfig, MaxNominations, Signature, StakerStatus, WASM_BINARY};
-use qchain_template_runtime::{CouncilConfig, ImOnlineConfig, NominationPoolsConfig, SessionConfig, StakingConfig};
+use qchain_template_runtime::{CouncilConfig, ImOnlineConfig, NominationPoolsConfig, SessionConfig, StakingConfig, VestingConfig};
+use qchain_template_runtime::{ExtendedAddressMapping, DAYS};
+use node_primitives::BlockNumber;
+use pallet_evm::AddressMapping;
 use qchain_template_runtime::AuthorityDiscoveryConfig;
 use qchain_template_runtime::constants::currency::DOLLARS;
 use sp_consensus_babe::AuthorityId as BabeId;
@@ -91,6 +94,29 @@ pub fn authority_keys_from_seed(
 	)
 }
 
+/// A genesis vesting schedule: `(account, begin, length, liquid)`, where the balance of
+/// `account` beyond `liquid` vests linearly over `length` blocks from block `begin`.
+pub type GenesisVesting = (AccountId, BlockNumber, BlockNumber, Balance);
+
+/// Vesting of the genesis EVM allocations, locked on the accounts their addresses map to.
+///
+/// The core team allocation vests over two years, the advisors allocations over a year.
+pub fn evm_allocations_vesting() -> Vec<GenesisVesting> {
+	[
+		// Core team
+		("8eaf04151687736326c9fea17e25fc5287613693", 2 * 365 * DAYS),
+		// Advisors
+		("6be02d1d3665660d22ff9624b7be0551ee1ac91b", 365 * DAYS),
+		("0Ebb2E8151b555fEBd4750300ffed34F32b8024d", 365 * DAYS),
+	]
+	.into_iter()
+	.map(|(address, length)| {
+		let address = H160::from_str(address).expect("internal H160 is valid; qed");
+		(ExtendedAddressMapping::into_account_id(address), 0, length, 0)
+	})
+	.collect()
+}
+
 fn session_keys(
 	grandpa: GrandpaId,
 	babe: BabeId,
@@ -126,6 +152,7 @@ pub fn development_config(enable_manual_seal: Option<bool>) -> DevChainSpec {
 					// Initial PoA authorities
 					vec![authority_keys_from_seed("Alice")],
 					vec![],
+					evm_allocations_vesting(),
 					42,
 				),
 				enable_manual_seal,
@@ -180,6 +207,7 @@ pub fn local_testnet_config() -> ChainSpec {
 					authority_keys_from_seed("Bob"),
 				],
 				vec![],
+				evm_allocations_vesting(),
 				42,
 			)
 		},
@@ -211,6 +239,7 @@ fn testnet_genesis(
 		AuthorityDiscoveryId,
 	)>,
 	initial_nominators: Vec<AccountId>,
+	vesting: Vec<GenesisVesting>,
 	chain_id: u64,
 ) -> GenesisConfig {
 	use qchain_template_runtime::{
@@ -365,6 +394,7 @@ fn testnet_genesis(
 			epoch_config: Some(qchain_template_runtime::BABE_GENESIS_EPOCH_CONFIG),
 		},
 		council: CouncilConfig::default(),
+		democracy: Default::default(),
 		im_online: ImOnlineConfig { keys: vec![] },
 		nomination_pools: NominationPoolsConfig {
 			min_create_bond: 10 * DOLLARS,
@@ -392,5 +422,6 @@ fn testnet_genesis(
 			..Default::default()
 		},
 		treasury: Default::default(),
+		vesting: VestingConfig { vesting },
 	}
 }
@@ -1,4 +1,7 @@
-use crate::service::EthConfiguration;
+use crate::{
+	onboarding::{FundCmd, NominatorCmd, ValidatorCmd},
+	service::EthConfiguration,
+};
 
 /// Available Sealing methods.
 #[derive(Debug, Copy, Clone, clap::ValueEnum)]
@@ -68,6 +71,26 @@ pub enum Subcommand {
 	#[cfg(not(feature = "runtime-benchmarks"))]
 	Benchmark,
 
+	/// Try some command against runtime state, such as the migrations of a runtime upgrade on a
+	/// snapshot of the chain state.
+	#[cfg(feature = "try-runtime")]
+	TryRuntime(try_runtime_cli::TryRuntimeCmd),
+
+	/// Try some command against runtime state. Note: `try-runtime` feature must be enabled.
+	#[cfg(not(feature = "try-runtime"))]
+	TryRuntime,
+
 	/// Db meta columns information.
 	FrontierDb(fc_cli::FrontierDbCmd),
+
+	/// Validator onboarding against a running node.
+	#[command(subcommand)]
+	Validator(ValidatorCmd),
+
+	/// Nominator onboarding against a running node.
+	#[command(subcommand)]
+	Nominator(NominatorCmd),
+
+	/// Transfer funds to one or more accounts through a running node.
+	Fund(FundCmd),
 }
@@ -68,6 +68,8 @@ pub trait RuntimeApiCollection:
 	+ sp_finality_grandpa::GrandpaApi<Block>
 	+ frame_system_rpc_runtime_api::AccountNonceApi<Block, AccountId, Index>
 	+ pallet_transaction_payment_rpc_runtime_api::TransactionPaymentApi<Block, Balance>
+	+ pallet_account_mapping_rpc::AccountMappingRuntimeApi<Block, AccountId>
+	+ pallet_staking_rpc::StakingRuntimeApi<Block, Balance, AccountId>
 where
 	<Self as sp_api::ApiExt<Block>>::StateBackend: sp_api::StateBackend<BlakeTwo256>,
 {
@@ -80,7 +82,9 @@ where
 		+ sp_consensus_babe::BabeApi<Block>
 		+ sp_finality_grandpa::GrandpaApi<Block>
 		+ frame_system_rpc_runtime_api::AccountNonceApi<Block, AccountId, Index>
-		+ pallet_transaction_payment_rpc_runtime_api::TransactionPaymentApi<Block, Balance>,
+		+ pallet_transaction_payment_rpc_runtime_api::TransactionPaymentApi<Block, Balance>
+		+ pallet_account_mapping_rpc::AccountMappingRuntimeApi<Block, AccountId>
+		+ pallet_staking_rpc::StakingRuntimeApi<Block, Balance, AccountId>,
 	<Self as sp_api::ApiExt<Block>>::StateBackend: sp_api::StateBackend<BlakeTwo256>,
 {
 }
@@ -67,6 +67,11 @@ pub struct EthConfiguration {
 	/// Size in bytes of the LRU cache for transactions statuses data.
 	#[arg(long, default_value = "50")]
 	pub eth_statuses_cache: usize,
+
+	/// How long the mapping sync worker waits for a block import before syncing anyway, in
+	/// milliseconds. Defaults to the block time reported by the runtime.
+	#[arg(long)]
+	pub frontier_sync_timeout: Option<u64>,
 }
 
 pub struct FrontierPartialComponents {
@@ -113,6 +118,7 @@ pub fn spawn_frontier_tasks<RuntimeApi, Executor>(
 	overrides: Arc<OverrideHandle<Block>>,
 	fee_history_cache: FeeHistoryCache,
 	fee_history_cache_limit: FeeHistoryCacheLimit,
+	sync_timeout: Duration,
 ) where
 	RuntimeApi: ConstructRuntimeApi<Block, FullClient<RuntimeApi, Executor>>,
 	RuntimeApi: Send + Sync + 'static,
@@ -125,7 +131,7 @@ pub fn spawn_frontier_tasks<RuntimeApi, Executor>(
 		Some("frontier"),
 		MappingSyncWorker::new(
 			client.import_notification_stream(),
-			Duration::new(6, 0),
+			sync_timeout,
 			client.clone(),
 			backend,
 			overrides.clone(),
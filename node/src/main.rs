@@ -15,6 +15,8 @@ mod cli;
 mod client;
 mod command;
 mod eth;
+mod metrics;
+mod onboarding;
 mod rpc;
 mod service;
 
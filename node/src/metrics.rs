@@ -0,0 +1,226 @@
+//! Prometheus metrics for EVM fee charging and fee reward distribution.
+//!
+//! Everything is derived from the runtime events (`pallet_evm_fees` in particular) and staking
+//! storage of each finalized block, so the numbers match what is on chain rather than what the
+//! runtime happened to log, and blocks retracted by a reorg are not counted.
+
+use std::sync::Arc;
+
+use futures::StreamExt;
+use scale_codec::{Decode, Encode};
+// Substrate
+use frame_support::traits::PalletInfoAccess;
+use frame_system::{EventRecord, Phase};
+use prometheus_endpoint::{register, Counter, Gauge, PrometheusError, Registry, F64, U64};
+use sc_client_api::{BlockchainEvents, StorageProvider};
+use sp_core::{
+	hashing::{twox_128, twox_64},
+	storage::StorageKey,
+};
+use sp_runtime::{DispatchError, ModuleError};
+// Local
+use qchain_template_runtime::{Balance, Hash, Runtime, RuntimeEvent, EVM};
+
+use crate::client::Client;
+
+/// EVM fee and fee reward metrics exported by the node.
+#[derive(Clone)]
+pub struct FeeMetrics {
+	evm_transactions_per_block: Gauge<U64>,
+	evm_transactions: Counter<U64>,
+	fees_collected: Counter<F64>,
+	fee_withdrawal_failures: Counter<U64>,
+	validator_fee_rewards: Gauge<F64>,
+	nominator_fee_rewards: Gauge<F64>,
+	eras_current_validator_reward: Gauge<F64>,
+	current_era: Gauge<U64>,
+}
+
+impl FeeMetrics {
+	pub fn register(registry: &Registry) -> Result<Self, PrometheusError> {
+		Ok(Self {
+			evm_transactions_per_block: register(
+				Gauge::new(
+					"qchain_evm_transactions_per_block",
+					"Number of EVM transactions in the latest finalized block",
+				)?,
+				registry,
+			)?,
+			evm_transactions: register(
+				Counter::new(
+					"qchain_evm_transactions_total",
+					"Total number of EVM transactions seen in finalized blocks",
+				)?,
+				registry,
+			)?,
+			fees_collected: register(
+				Counter::new(
+					"qchain_evm_fees_collected_total",
+					"Total EVM transaction fees withdrawn from payers",
+				)?,
+				registry,
+			)?,
+			fee_withdrawal_failures: register(
+				Counter::new(
+					"qchain_evm_fee_withdrawal_failures_total",
+					"Total number of EVM extrinsics that failed to pay their fee",
+				)?,
+				registry,
+			)?,
+			validator_fee_rewards: register(
+				Gauge::new(
+					"qchain_evm_validator_fee_rewards_current_era",
+					"EVM fee rewards paid out to block authors in the current era",
+				)?,
+				registry,
+			)?,
+			nominator_fee_rewards: register(
+				Gauge::new(
+					"qchain_evm_nominator_fee_rewards_current_era",
+					"EVM fee rewards paid out to nominators in the current era",
+				)?,
+				registry,
+			)?,
+			eras_current_validator_reward: register(
+				Gauge::new(
+					"qchain_eras_current_validator_reward_current_era",
+					"Sum of ErasCurrentValidatorReward over all validators in the current era",
+				)?,
+				registry,
+			)?,
+			current_era: register(
+				Gauge::new("qchain_staking_current_era", "Current staking era")?,
+				registry,
+			)?,
+		})
+	}
+}
+
+/// Fee movements of a single EVM transaction, as reported by its events.
+#[derive(Default)]
+struct FeeDistribution {
+	evm_transaction: bool,
+	fee: Balance,
+	validator_reward: Balance,
+	nominator_reward: Balance,
+}
+
+impl FeeDistribution {
+	fn note(&mut self, event: &RuntimeEvent) {
+		match event {
+			RuntimeEvent::Ethereum(pallet_ethereum::Event::Executed { .. }) |
+			RuntimeEvent::EVM(pallet_evm::Event::Executed { .. }) |
+			RuntimeEvent::EVM(pallet_evm::Event::ExecutedFailed { .. }) |
+			RuntimeEvent::EVM(pallet_evm::Event::Created { .. }) |
+			RuntimeEvent::EVM(pallet_evm::Event::CreatedFailed { .. }) => self.evm_transaction = true,
+			RuntimeEvent::EvmFees(pallet_evm_fees::Event::FeeCharged { amount, .. }) => {
+				self.fee = self.fee.saturating_add(*amount)
+			},
+			RuntimeEvent::EvmFees(pallet_evm_fees::Event::ValidatorFeeReward { amount, .. }) => {
+				self.validator_reward = self.validator_reward.saturating_add(*amount)
+			},
+			RuntimeEvent::EvmFees(pallet_evm_fees::Event::NominatorFeeReward { amount, .. }) => {
+				self.nominator_reward = self.nominator_reward.saturating_add(*amount)
+			},
+			_ => {},
+		}
+	}
+}
+
+/// Whether `error` is one of the errors `EVMConstFeeAdapter::withdraw_fee` reports.
+fn is_fee_withdrawal_error(error: &DispatchError) -> bool {
+	match error {
+		DispatchError::Module(ModuleError { index, error, .. }) if *index as usize == EVM::index() =>
+			[
+				pallet_evm::Error::<Runtime>::BalanceLow,
+				pallet_evm::Error::<Runtime>::FeeOverflow,
+				pallet_evm::Error::<Runtime>::Undefined,
+			]
+			.iter()
+			.any(|e| e.encode().first() == error.first()),
+		_ => false,
+	}
+}
+
+fn storage_prefix(pallet: &str, storage: &str) -> Vec<u8> {
+	[twox_128(pallet.as_bytes()), twox_128(storage.as_bytes())].concat()
+}
+
+/// Update `metrics` with the content of every block finalized by `client`, in order.
+pub async fn run(client: Arc<Client>, metrics: FeeMetrics) {
+	let mut notifications = client.finality_notification_stream();
+	let mut tracked_era = None;
+
+	while let Some(notification) = notifications.next().await {
+		// A notification finalizes the blocks of its tree route implicitly.
+		for hash in notification.tree_route.iter().chain(std::iter::once(&notification.hash)) {
+			if let Err(e) = update(&client, *hash, &metrics, &mut tracked_era) {
+				log::debug!(target: "metrics", "Failed to collect EVM fee metrics: {}", e);
+			}
+		}
+	}
+}
+
+fn update(
+	client: &Client,
+	hash: Hash,
+	metrics: &FeeMetrics,
+	tracked_era: &mut Option<u32>,
+) -> sp_blockchain::Result<()> {
+	let current_era = client
+		.storage(hash, &StorageKey(storage_prefix("Staking", "CurrentEra")))?
+		.and_then(|data| u32::decode(&mut &data.0[..]).ok())
+		.unwrap_or_default();
+	if *tracked_era != Some(current_era) {
+		*tracked_era = Some(current_era);
+		metrics.current_era.set(current_era as u64);
+		metrics.validator_fee_rewards.set(0.0);
+		metrics.nominator_fee_rewards.set(0.0);
+	}
+
+	let events = client
+		.storage(hash, &StorageKey(storage_prefix("System", "Events")))?
+		.and_then(|data| {
+			Vec::<EventRecord<RuntimeEvent, Hash>>::decode(&mut &data.0[..]).ok()
+		})
+		.unwrap_or_default();
+
+	let mut extrinsics = std::collections::BTreeMap::<u32, FeeDistribution>::new();
+	for record in &events {
+		let Phase::ApplyExtrinsic(index) = record.phase else { continue };
+		if let RuntimeEvent::System(frame_system::Event::ExtrinsicFailed { dispatch_error, .. }) =
+			&record.event
+		{
+			if is_fee_withdrawal_error(dispatch_error) {
+				metrics.fee_withdrawal_failures.inc();
+			}
+		}
+		extrinsics.entry(index).or_default().note(&record.event);
+	}
+
+	let mut evm_transactions = 0u64;
+	for distribution in extrinsics.values() {
+		// Fee rewards are paid out by `Staking::payout_fee_rewards` rather than by EVM
+		// transactions.
+		metrics.validator_fee_rewards.add(distribution.validator_reward as f64);
+		metrics.nominator_fee_rewards.add(distribution.nominator_reward as f64);
+		if distribution.evm_transaction {
+			evm_transactions += 1;
+			metrics.fees_collected.inc_by(distribution.fee as f64);
+		}
+	}
+	metrics.evm_transactions_per_block.set(evm_transactions);
+	metrics.evm_transactions.inc_by(evm_transactions);
+
+	let mut reward_prefix = storage_prefix("Staking", "ErasCurrentValidatorReward");
+	reward_prefix.extend(twox_64(&current_era.encode()));
+	reward_prefix.extend(current_era.encode());
+	let total_reward = client
+		.storage_pairs(hash, &StorageKey(reward_prefix))?
+		.into_iter()
+		.filter_map(|(_, data)| Balance::decode(&mut &data.0[..]).ok())
+		.fold(0 as Balance, |total, reward| total.saturating_add(reward));
+	metrics.eras_current_validator_reward.set(total_reward as f64);
+
+	Ok(())
+}
@@ -0,0 +1,395 @@
+//! Native replacements for the `init_chain` scripts: onboarding validators and nominators,
+//! and funding accounts on a running network straight from the node binary.
+
+use scale_codec::{Decode, Encode};
+use serde::Deserialize;
+// Substrate
+use jsonrpsee::{
+	core::client::ClientT,
+	rpc_params,
+	ws_client::{WsClient, WsClientBuilder},
+};
+use sc_cli::{KeystoreParams, Result, SharedParams, SubstrateCli};
+use sc_keystore::LocalKeystore;
+use sc_service::config::{BasePath, KeystoreConfig};
+use sp_core::{
+	crypto::{key_types::ACCOUNT, ByteArray, Ss58Codec},
+	sr25519, Bytes, Pair, H256,
+};
+use sp_keystore::SyncCryptoStore;
+use sp_runtime::{generic::Era, Perbill};
+// Local
+use qchain_template_runtime::{
+	self as runtime, opaque::SessionKeys, AccountId, Balance, BalancesCall, Index,
+	RewardDestination, SessionCall, StakingCall, UtilityCall, ValidatorPrefs,
+};
+
+/// Parameters shared by every command that signs and submits an extrinsic.
+#[derive(Debug, Clone, clap::Parser)]
+pub struct SubmitParams {
+	/// WebSocket endpoint of the node the extrinsic is submitted to.
+	#[arg(long, default_value = "ws://127.0.0.1:9944")]
+	pub url: String,
+
+	/// Secret URI of the signing account.
+	///
+	/// When omitted, the key for `--account` is taken from the node keystore.
+	#[arg(long, conflicts_with = "account")]
+	pub suri: Option<String>,
+
+	/// SS58 address of an sr25519 account key stored in the node keystore.
+	#[arg(long, value_parser = parse_account, required_unless_present = "suri")]
+	pub account: Option<AccountId>,
+
+	#[allow(missing_docs)]
+	#[command(flatten)]
+	pub shared_params: SharedParams,
+
+	#[allow(missing_docs)]
+	#[command(flatten)]
+	pub keystore_params: KeystoreParams,
+}
+
+/// Validator onboarding utilities.
+#[derive(Debug, clap::Subcommand)]
+pub enum ValidatorCmd {
+	/// Bond the signing account, set its session keys and start validating.
+	Register(RegisterValidatorCmd),
+}
+
+/// Nominator onboarding utilities.
+#[derive(Debug, clap::Subcommand)]
+pub enum NominatorCmd {
+	/// Bond the signing account and nominate the given validators.
+	Add(AddNominatorCmd),
+}
+
+#[derive(Debug, Clone, clap::Parser)]
+pub struct RegisterValidatorCmd {
+	/// Amount to bond, in the smallest unit.
+	#[arg(long)]
+	pub bond: Balance,
+
+	/// Validator commission, in parts per billion.
+	#[arg(long)]
+	pub commission: u32,
+
+	/// Refuse new nominations.
+	#[arg(long)]
+	pub blocked: bool,
+
+	/// SCALE-encoded session keys in hex.
+	///
+	/// When omitted, fresh keys are generated with `author_rotateKeys` on the target node.
+	#[arg(long)]
+	pub session_keys: Option<Bytes>,
+
+	#[allow(missing_docs)]
+	#[command(flatten)]
+	pub submit: SubmitParams,
+}
+
+#[derive(Debug, Clone, clap::Parser)]
+pub struct AddNominatorCmd {
+	/// Amount to bond, in the smallest unit.
+	#[arg(long)]
+	pub bond: Balance,
+
+	/// Stash addresses of the validators to nominate.
+	#[arg(long = "target", value_parser = parse_account, required = true)]
+	pub targets: Vec<AccountId>,
+
+	/// Hand the ledger over to a different controller once bonded.
+	#[arg(long, value_parser = parse_account)]
+	pub controller: Option<AccountId>,
+
+	#[allow(missing_docs)]
+	#[command(flatten)]
+	pub submit: SubmitParams,
+}
+
+#[derive(Debug, Clone, clap::Parser)]
+pub struct FundCmd {
+	/// Accounts to transfer to.
+	#[arg(long = "dest", value_parser = parse_account, required = true)]
+	pub dests: Vec<AccountId>,
+
+	/// Amount transferred to each account, in the smallest unit.
+	#[arg(long)]
+	pub amount: Balance,
+
+	#[allow(missing_docs)]
+	#[command(flatten)]
+	pub submit: SubmitParams,
+}
+
+impl ValidatorCmd {
+	pub fn run<C: SubstrateCli>(&self, cli: &C) -> Result<()> {
+		match self {
+			ValidatorCmd::Register(cmd) => cmd.run(cli),
+		}
+	}
+}
+
+impl NominatorCmd {
+	pub fn run<C: SubstrateCli>(&self, cli: &C) -> Result<()> {
+		match self {
+			NominatorCmd::Add(cmd) => cmd.run(cli),
+		}
+	}
+}
+
+impl RegisterValidatorCmd {
+	pub fn run<C: SubstrateCli>(&self, cli: &C) -> Result<()> {
+		let signer = self.submit.signer(cli)?;
+		let stash = signer.account_id();
+
+		block_on(async {
+			let client = self.submit.connect().await?;
+			let keys = match &self.session_keys {
+				Some(keys) => keys.clone(),
+				None => client
+					.request::<Bytes, _>("author_rotateKeys", rpc_params![])
+					.await
+					.map_err(rpc_error)?,
+			};
+			let keys = SessionKeys::decode(&mut &keys[..])
+				.map_err(|e| format!("Invalid session keys: {}", e))?;
+
+			let call = UtilityCall::<runtime::Runtime>::batch_all {
+				calls: vec![
+					StakingCall::bond {
+						controller: stash.into(),
+						value: self.bond,
+						payee: RewardDestination::Staked,
+					}
+					.into(),
+					SessionCall::set_keys { keys, proof: vec![] }.into(),
+					StakingCall::validate {
+						prefs: ValidatorPrefs {
+							commission: Perbill::from_parts(self.commission),
+							blocked: self.blocked,
+						},
+					}
+					.into(),
+				],
+			};
+			let hash = sign_and_submit(&client, &signer, call.into()).await?;
+			println!("Registered validator with hash {:?}", hash);
+			Ok(())
+		})
+	}
+}
+
+impl AddNominatorCmd {
+	pub fn run<C: SubstrateCli>(&self, cli: &C) -> Result<()> {
+		let signer = self.submit.signer(cli)?;
+		let stash = signer.account_id();
+
+		block_on(async {
+			let client = self.submit.connect().await?;
+			let mut calls: Vec<runtime::RuntimeCall> = vec![
+				StakingCall::bond {
+					controller: stash.into(),
+					value: self.bond,
+					payee: RewardDestination::Staked,
+				}
+				.into(),
+				StakingCall::nominate {
+					targets: self.targets.iter().cloned().map(Into::into).collect(),
+				}
+				.into(),
+			];
+			if let Some(controller) = &self.controller {
+				calls.push(
+					StakingCall::set_controller { controller: controller.clone().into() }.into(),
+				);
+			}
+
+			let call = UtilityCall::<runtime::Runtime>::batch_all { calls };
+			let hash = sign_and_submit(&client, &signer, call.into()).await?;
+			println!("Added nominator with hash {:?}", hash);
+			Ok(())
+		})
+	}
+}
+
+impl FundCmd {
+	pub fn run<C: SubstrateCli>(&self, cli: &C) -> Result<()> {
+		let signer = self.submit.signer(cli)?;
+
+		block_on(async {
+			let client = self.submit.connect().await?;
+			let calls: Vec<runtime::RuntimeCall> = self
+				.dests
+				.iter()
+				.map(|dest| {
+					BalancesCall::transfer { dest: dest.clone().into(), value: self.amount }.into()
+				})
+				.collect();
+
+			let call = UtilityCall::<runtime::Runtime>::batch_all { calls };
+			let hash = sign_and_submit(&client, &signer, call.into()).await?;
+			println!("Submitted transfers with hash {:?}", hash);
+			Ok(())
+		})
+	}
+}
+
+/// The account an extrinsic is signed with.
+enum Signer {
+	/// A key pair derived from a secret URI given on the command line.
+	Pair(sr25519::Pair),
+	/// An account key held in the node keystore.
+	Keystore(LocalKeystore, sr25519::Public),
+}
+
+impl Signer {
+	fn public(&self) -> sr25519::Public {
+		match self {
+			Signer::Pair(pair) => pair.public(),
+			Signer::Keystore(_, public) => *public,
+		}
+	}
+
+	fn account_id(&self) -> AccountId {
+		self.public().into()
+	}
+
+	fn sign(&self, payload: &[u8]) -> Result<sr25519::Signature> {
+		match self {
+			Signer::Pair(pair) => Ok(pair.sign(payload)),
+			Signer::Keystore(keystore, public) => {
+				let signature =
+					SyncCryptoStore::sign_with(keystore, ACCOUNT, &(*public).into(), payload)
+						.map_err(|e| format!("Keystore signing failed: {}", e))?
+						.ok_or_else(|| {
+							format!("Key {} not found in keystore", public.to_ss58check())
+						})?;
+				sr25519::Signature::from_slice(&signature)
+					.ok_or_else(|| "Keystore returned a malformed signature".into())
+			}
+		}
+	}
+}
+
+impl SubmitParams {
+	fn signer<C: SubstrateCli>(&self, cli: &C) -> Result<Signer> {
+		if let Some(suri) = &self.suri {
+			let pair = sr25519::Pair::from_string(suri, None)
+				.map_err(|e| format!("Invalid secret URI: {:?}", e))?;
+			return Ok(Signer::Pair(pair))
+		}
+
+		let account = self.account.clone().ok_or("Either --suri or --account is required")?;
+		let public = sr25519::Public::from_slice(account.as_ref())
+			.map_err(|_| "Invalid account public key")?;
+
+		let base_path = self
+			.shared_params
+			.base_path()?
+			.unwrap_or_else(|| BasePath::from_project("", "", &C::executable_name()));
+		let chain_id = self.shared_params.chain_id(self.shared_params.is_dev());
+		let chain_spec = cli.load_spec(&chain_id)?;
+		let config_dir = base_path.config_dir(chain_spec.id());
+
+		let keystore = match self.keystore_params.keystore_config(&config_dir)? {
+			KeystoreConfig::Path { path, password } => LocalKeystore::open(path, password)?,
+			_ => unreachable!("keystore_config always returns path and password; qed"),
+		};
+		if !SyncCryptoStore::has_keys(&keystore, &[(public.to_raw_vec(), ACCOUNT)]) {
+			return Err(format!("Key {} not found in keystore", public.to_ss58check()).into())
+		}
+
+		Ok(Signer::Keystore(keystore, public))
+	}
+
+	async fn connect(&self) -> Result<WsClient> {
+		WsClientBuilder::default().build(&self.url).await.map_err(rpc_error)
+	}
+}
+
+/// The part of `state_getRuntimeVersion` needed to sign an extrinsic.
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct RuntimeVersion {
+	spec_version: u32,
+	transaction_version: u32,
+}
+
+/// Sign `call` with `signer` as an immortal transaction and submit it through `client`.
+async fn sign_and_submit(
+	client: &WsClient,
+	signer: &Signer,
+	call: runtime::RuntimeCall,
+) -> Result<H256> {
+	let account = signer.account_id();
+	let genesis_hash = client
+		.request::<Option<H256>, _>("chain_getBlockHash", rpc_params![0u32])
+		.await
+		.map_err(rpc_error)?
+		.ok_or("Genesis block not found")?;
+	let version = client
+		.request::<RuntimeVersion, _>("state_getRuntimeVersion", rpc_params![])
+		.await
+		.map_err(rpc_error)?;
+	let nonce = client
+		.request::<Index, _>("system_accountNextIndex", rpc_params![account.to_ss58check()])
+		.await
+		.map_err(rpc_error)?;
+
+	let extra: runtime::SignedExtra = (
+		frame_system::CheckNonZeroSender::<runtime::Runtime>::new(),
+		frame_system::CheckSpecVersion::<runtime::Runtime>::new(),
+		frame_system::CheckTxVersion::<runtime::Runtime>::new(),
+		frame_system::CheckGenesis::<runtime::Runtime>::new(),
+		frame_system::CheckMortality::<runtime::Runtime>::from(Era::Immortal),
+		frame_system::CheckNonce::<runtime::Runtime>::from(nonce),
+		frame_system::CheckWeight::<runtime::Runtime>::new(),
+		pallet_transaction_payment::ChargeTransactionPayment::<runtime::Runtime>::from(0),
+	);
+
+	let raw_payload = runtime::SignedPayload::from_raw(
+		call.clone(),
+		extra.clone(),
+		(
+			(),
+			version.spec_version,
+			version.transaction_version,
+			genesis_hash,
+			genesis_hash,
+			(),
+			(),
+			(),
+		),
+	);
+	let signature = raw_payload.using_encoded(|payload| signer.sign(payload))?;
+
+	let extrinsic = runtime::UncheckedExtrinsic::new_signed(
+		call,
+		account.into(),
+		runtime::Signature::Sr25519(signature),
+		extra,
+	);
+
+	client
+		.request::<H256, _>("author_submitExtrinsic", rpc_params![Bytes(extrinsic.encode())])
+		.await
+		.map_err(rpc_error)
+}
+
+fn parse_account(s: &str) -> std::result::Result<AccountId, String> {
+	AccountId::from_ss58check(s).map_err(|e| format!("Invalid SS58 address {}: {:?}", s, e))
+}
+
+fn rpc_error(e: jsonrpsee::core::Error) -> sc_cli::Error {
+	format!("RPC request failed: {}", e).into()
+}
+
+fn block_on<F: std::future::Future<Output = Result<()>>>(future: F) -> Result<()> {
+	tokio::runtime::Builder::new_current_thread()
+		.enable_all()
+		.build()
+		.map_err(|e| format!("Failed to start async runtime: {}", e))?
+		.block_on(future)
+}
@@ -101,6 +101,8 @@ where
 	C::Api: fp_rpc::EthereumRuntimeRPCApi<Block>,
 	// C::Api: mmr_rpc::MmrRuntimeApi<Block, <Block as sp_runtime::traits::Block>::Hash, BlockNumber>,
 	C::Api: BabeApi<Block>,
+	C::Api: pallet_account_mapping_rpc::AccountMappingRuntimeApi<Block, AccountId>,
+	C::Api: pallet_staking_rpc::StakingRuntimeApi<Block, Balance, AccountId>,
 	SC: SelectChain<Block> + 'static,
 	C: BlockchainEvents<Block> + 'static,
 	C: HeaderBackend<Block>
@@ -111,6 +113,8 @@ where
 	A: ChainApi<Block = Block> + 'static,
 	CT: fp_rpc::ConvertTransaction<<Block as BlockT>::Extrinsic> + Send + Sync + 'static,
 {
+	use pallet_account_mapping_rpc::{AccountMapping, AccountMappingApiServer};
+	use pallet_staking_rpc::{Staking, StakingApiServer};
 	use pallet_transaction_payment_rpc::{TransactionPayment, TransactionPaymentApiServer};
 	use sc_consensus_manual_seal::rpc::{ManualSeal, ManualSealApiServer};
 	use substrate_frame_rpc_system::{System, SystemApiServer};
@@ -141,6 +145,8 @@ where
 	io.merge(System::new(client.clone(), pool, deny_unsafe).into_rpc())?;
 	// io.merge(Mmr::new(client.clone()).into_rpc())?;
 	io.merge(TransactionPayment::new(client.clone()).into_rpc())?;
+	io.merge(AccountMapping::new(client.clone()).into_rpc())?;
+	io.merge(Staking::new(client.clone()).into_rpc())?;
 
 	io.merge(
 		Babe::new(
@@ -16,7 +16,9 @@ use sc_network_common::protocol::event::Event;
 use sc_network_common::sync::warp::WarpSyncParams;
 use sc_service::{error::Error as ServiceError, Configuration, PartialComponents, TaskManager, GenesisBlockBuilder, new_db_backend, BuildGenesisBlock, resolve_state_version_from_wasm};
 use sc_telemetry::{Telemetry, TelemetryHandle, TelemetryWorker};
-use sp_api::{ConstructRuntimeApi, TransactionFor};
+use pallet_staking_rpc::{StakingRuntimeApi, Timing};
+use sp_api::{ApiExt, ConstructRuntimeApi, ProvideRuntimeApi, TransactionFor};
+use sp_blockchain::HeaderBackend;
 use sp_consensus_aura::sr25519::AuthorityPair as AuraPair;
 use sp_consensus_babe::BabeApi;
 use sc_consensus_babe::{self, SlotProportion};
@@ -50,6 +52,46 @@ type GrandpaBlockImport<Client> =
 type GrandpaLinkHalf<Client> = sc_finality_grandpa::LinkHalf<Block, Client, FullSelectChain>;
 type BoxBlockImport<Client> = sc_consensus::BoxBlockImport<Block, TransactionFor<Client, Block>>;
 
+/// The timing reported by the runtime at the best block.
+///
+/// Runtimes that predate version 2 of `StakingApi` do not report it, the timing compiled in the
+/// node is used instead.
+fn runtime_timing<C>(client: &C) -> Timing
+where
+	C: ProvideRuntimeApi<Block> + HeaderBackend<Block>,
+	C::Api: StakingRuntimeApi<Block, Balance, AccountId>,
+{
+	let best_hash = client.info().best_hash;
+	let api = client.runtime_api();
+	let has_timing = api
+		.has_api_with::<dyn StakingRuntimeApi<Block, Balance, AccountId>, _>(best_hash, |version| {
+			version >= 2
+		});
+	let timing = match has_timing {
+		Ok(true) => api.timing(best_hash).map_err(|e| e.to_string()),
+		Ok(false) => Err("the runtime does not report its timing".into()),
+		Err(e) => Err(e.to_string()),
+	};
+	timing.unwrap_or_else(|e| {
+		log::warn!("Using the timing compiled in the node: {}", e);
+		compiled_timing()
+	})
+}
+
+/// The timing of the runtime compiled in the node.
+fn compiled_timing() -> Timing {
+	use qchain_template_runtime::constants::time::*;
+
+	Timing {
+		block_time: MILLISECS_PER_BLOCK,
+		slot_duration: SLOT_DURATION,
+		epoch_duration: EPOCH_DURATION_IN_SLOTS,
+		session_duration: EPOCH_DURATION_IN_SLOTS * SLOT_DURATION,
+		sessions_per_era: SESSIONS_PER_ERA,
+		era_duration: ERA_DURATION,
+	}
+}
+
 pub fn new_partial<RuntimeApi, Executor>(
 	config: &Configuration,
 ) -> Result<
@@ -481,6 +523,20 @@ pub fn new_full
 		telemetry: telemetry.as_mut(),
 	})?;
 
+	if let Some(registry) = prometheus_registry.as_ref() {
+		match crate::metrics::FeeMetrics::register(registry) {
+			Ok(metrics) => task_manager.spawn_handle().spawn(
+				"evm-fee-metrics",
+				None,
+				crate::metrics::run(client.clone(), metrics),
+			),
+			Err(e) => log::warn!("Failed to register EVM fee metrics: {}", e),
+		}
+	}
+
+	let timing = runtime_timing(&*client);
+	let sync_timeout = eth_config.frontier_sync_timeout.unwrap_or(timing.block_time);
+
 	spawn_frontier_tasks(
 		&task_manager,
 		client.clone(),
@@ -490,6 +546,7 @@ pub fn new_full
 		overrides,
 		fee_history_cache,
 		fee_history_cache_limit,
+		Duration::from_millis(sync_timeout),
 	);
 
 	if let sc_service::config::Role::Authority { .. } = &role {
@@ -654,8 +711,8 @@ where
 	thread_local!(static TIMESTAMP: RefCell<u64> = RefCell::new(0));
 
 	/// Provide a mock duration starting at 0 in millisecond for timestamp inherent.
-	/// Each call will increment timestamp by slot_duration making Aura think time has passed.
-	struct MockTimestampInherentDataProvider;
+	/// Each call will increment timestamp by the slot duration making BABE think time has passed.
+	struct MockTimestampInherentDataProvider(u64);
 
 	#[async_trait::async_trait]
 	impl sp_inherents::InherentDataProvider for MockTimestampInherentDataProvider {
@@ -664,7 +721,7 @@ where
 			inherent_data: &mut sp_inherents::InherentData,
 		) -> Result<(), sp_inherents::Error> {
 			TIMESTAMP.with(|x| {
-				*x.borrow_mut() += qchain_template_runtime::SLOT_DURATION;
+				*x.borrow_mut() += self.0;
 				inherent_data.put_data(sp_timestamp::INHERENT_IDENTIFIER, &*x.borrow())
 			})
 		}
@@ -679,9 +736,10 @@ where
 		}
 	}
 
+	let slot_duration = runtime_timing(&*client).slot_duration;
 	let target_gas_price = eth_config.target_gas_price;
 	let create_inherent_data_providers = move |_, ()| async move {
-		let timestamp = MockTimestampInherentDataProvider;
+		let timestamp = MockTimestampInherentDataProvider(slot_duration);
 		let dynamic_fee = fp_dynamic_fee::InherentDataProvider(U256::from(target_gas_price));
 		Ok((timestamp, dynamic_fee))
 	};
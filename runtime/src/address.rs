@@ -6,6 +6,7 @@ use sp_core::crypto::AccountId32;
 use sp_core::{H160};
 use sp_runtime::ConsensusEngineId;
 use sp_core_hashing::keccak_256;
+use pallet_staking::address_mapping::AccountMapping;
 
 pub struct EnsureAddressHashing;
 
@@ -24,3 +25,21 @@ impl<OuterOrigin> EnsureAddressOrigin<OuterOrigin> for EnsureAddressHashing
         })
     }
 }
+
+/// Native accounts linked to an EVM address through `pallet_account_mapping` are reachable from
+/// the EVM as themselves, any other account is mapped by `F`.
+pub struct LinkedAccountMapping<T, F>(PhantomData<(T, F)>);
+
+impl<T, F> AccountMapping<AccountId32> for LinkedAccountMapping<T, F>
+    where
+        T: pallet_account_mapping::Config<AccountId = AccountId32>,
+        F: AccountMapping<AccountId32>,
+{
+    fn into_account_id(address: AccountId32) -> AccountId32 {
+        if pallet_account_mapping::EvmAddresses::<T>::contains_key(&address) {
+            address
+        } else {
+            F::into_account_id(address)
+        }
+    }
+}
@@ -9,6 +9,7 @@ use frame_support::log;
 use sp_staking::StakingInterface;
 use frame_election_provider_support::ElectionDataProvider;
 use pallet_staking::NominatorsHandle;
+use pallet_evm_fees::Pallet as EvmFees;
 
 type NegativeImbalanceOf<C, T> =
     <C as Currency<<T as frame_system::Config>::AccountId>>::NegativeImbalance;
@@ -19,7 +20,11 @@ const CONST_TRANSACTION_FEE: u128 = 10000000000000000000;
 
 impl<T, C, OU, S> OnChargeEVMTransaction<T> for EVMConstFeeAdapter<C, OU, S>
     where
-        T: Config + pallet_staking::Config<CurrencyBalance = u128> + pallet_babe::Config + pallet_session::Config,
+        T: Config
+            + pallet_staking::Config<CurrencyBalance = u128>
+            + pallet_babe::Config
+            + pallet_session::Config
+            + pallet_evm_fees::Config,
         C: Currency<<T as frame_system::Config>::AccountId, Balance = u128>,
         S: StakingInterface<
             AccountId = <T as frame_system::Config>::AccountId,
@@ -37,7 +42,6 @@ impl<T, C, OU, S> OnChargeEVMTransaction<T> for EVMConstFeeAdapter<C, OU, S>
         OU: OnUnbalanced<NegativeImbalanceOf<C, T>>,
         U256: UniqueSaturatedInto<<C as Currency<<T as frame_system::Config>::AccountId>>::Balance>,
         <T as frame_system::Config>::AccountId: From<sp_core::sr25519::Public>,
-        <T as frame_system::Config>::AccountId: From<<T as pallet_session::Config>::ValidatorId>
 {
     // Kept type as Option to satisfy bound of Default
     type LiquidityInfo = Option<NegativeImbalanceOf<C, T>>;
@@ -57,50 +61,28 @@ impl<T, C, OU, S> OnChargeEVMTransaction<T> for EVMConstFeeAdapter<C, OU, S>
             })?;
 
         log::info!("payer: {:?} {:?}", payer, who);
+        EvmFees::<T>::fee_charged(&payer, *who, imbalance.peek().unique_saturated_into());
 
-        let validator = <pallet_staking::Pallet<T>>::author().ok_or_else(
+        let (validator, _) = S::author().ok_or_else(
             || {
                 log::error!("Failed to find block author");
                 Error::<T>::Undefined
             }
-        )?.into();
+        )?;
 
         S::insert_validator_rewards(&validator, CONST_TRANSACTION_FEE).map_err(|err| {
             log::error!("Error while insert validator rewards: {:?}", err);
             Error::<T>::FeeOverflow
-        })?;;
-
-        let fee = U256::from(CONST_TRANSACTION_FEE / 10).unique_saturated_into();
-        log::info!("Validator: {:?}, fee: {:?}", validator, fee);
-        let _ = C::deposit_creating(&validator, fee);
-
-        let exposure = S::get_nominators_shares(&validator).map_err(|err| {
-            log::error!("Error while get nominators: {}", err);
-            Error::<T>::Undefined
         })?;
-        let mut stakers_fee = CONST_TRANSACTION_FEE * 9 / 10;
-        if let Some(exposure) = exposure {
-            let staked = exposure.total - exposure.own;
-
-            for staker in exposure.others {
-                let staker_fee = ((CONST_TRANSACTION_FEE * 9 / 10) as f64 / staked as f64 * staker.value as f64) as u128;
-                let staker_fee = if staker_fee < stakers_fee {
-                    stakers_fee -= staker_fee;
-                    staker_fee
-                } else {
-                    let fee = stakers_fee;
-                    stakers_fee = 0;
-                    fee
-                };
-                log::info!("Staker: {:?}, fee: {:?}", staker.who, staker_fee);
-                C::deposit_creating(&staker.who, U256::from(staker_fee).unique_saturated_into());
-            }
-        }
 
-        if stakers_fee != 0 {
-            log::info!("Validator: {:?}, fee: {:?}", validator, stakers_fee);
-            C::deposit_creating(&validator,  U256::from(stakers_fee).unique_saturated_into());
-        }
+        // The fee is split between the author and its nominators when it is paid out through
+        // the staking payout, see `Staking::payout_fee_rewards`, so that charging it does not
+        // depend on the number of nominators. Nomination pools bond with their reward account as
+        // reward destination, so the shares of a pool end up there to be claimable by the pool
+        // members.
+        log::info!("Validator: {:?}, fee: {:?}", validator, CONST_TRANSACTION_FEE);
+        <pallet_staking::Pallet<T>>::credit_fee_reward(&validator, CONST_TRANSACTION_FEE)
+            .unwrap_or_else(|err| log::error!("Error while crediting fee reward: {:?}", err));
 
         Ok(Some(imbalance))
     }
@@ -119,10 +101,10 @@ impl<T, C, OU, S> OnChargeEVMTransaction<T> for EVMConstFeeAdapter<C, OU, S>
     }
 }
 
-
 #[cfg(test)]
 mod tests {
     use crate::const_evm_transaction::CONST_TRANSACTION_FEE;
+    use pallet_staking::fees::split_fee;
 
     struct Exposure {
         pub total: u128,
@@ -137,18 +119,13 @@ mod tests {
             own: 999999999956657370
         };
         let staker_value: u128 = 99999999999999970905012;
-        let mut stakers_fee = CONST_TRANSACTION_FEE * 9 / 10;
         let staked = exposure.total - exposure.own;
 
-        let staker_fee: u128 = ((CONST_TRANSACTION_FEE * 9 / 10) as f64 / staked as f64 * staker_value as f64) as u128;
-        let staker_fee = if staker_fee < stakers_fee {
-            stakers_fee -= staker_fee;
-            staker_fee
-        } else {
-            let fee = stakers_fee;
-            stakers_fee = 0;
-            fee
-        };
-        assert_eq!(staker_fee, 900000000000000000);
+        let split = split_fee(CONST_TRANSACTION_FEE, staked, [(1u64, staker_value)]);
+        // The only nominator gets all of the nominators' share, despite the rounding of its
+        // share upwards.
+        assert_eq!(split.author, 1000000000000000000);
+        assert_eq!(split.nominators, vec![(1, 9000000000000000000)]);
+        assert_eq!(split.remainder, 0);
     }
 }
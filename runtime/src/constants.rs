@@ -31,6 +31,10 @@ pub mod currency {
 }
 
 /// Time.
+///
+/// The single source of the timing of the chain: BABE, the timestamp, sessions and staking eras
+/// are all configured from these constants, which the node reads through
+/// `StakingApi::timing`.
 pub mod time {
 	use node_primitives::{BlockNumber, Moment};
 
@@ -51,7 +55,7 @@ pub mod time {
 	/// `SLOT_DURATION` should have the same value.
 	///
 	/// <https://research.web3.foundation/en/latest/polkadot/block-production/Babe.html#-6.-practical-results>
-	pub const MILLISECS_PER_BLOCK: Moment = 3000;
+	pub const MILLISECS_PER_BLOCK: Moment = 6000;
 	pub const SECS_PER_BLOCK: Moment = MILLISECS_PER_BLOCK / 1000;
 
 	// NOTE: Currently it is not possible to change the slot duration after the chain has started.
@@ -63,15 +67,21 @@ pub mod time {
 
 	// NOTE: Currently it is not possible to change the epoch duration after the chain has started.
 	//       Attempting to do so will brick block production.
-	pub const EPOCH_DURATION_IN_BLOCKS: BlockNumber = 1 * MINUTES;
+	pub const EPOCH_DURATION_IN_BLOCKS: BlockNumber = 2 * MINUTES;
 	pub const EPOCH_DURATION_IN_SLOTS: u64 = {
 		const SLOT_FILL_RATE: f64 = MILLISECS_PER_BLOCK as f64 / SLOT_DURATION as f64;
 
 		(EPOCH_DURATION_IN_BLOCKS as f64 * SLOT_FILL_RATE) as u64
 	};
 
+	/// Sessions follow BABE epochs, an era lasts this many of them.
+	pub const SESSIONS_PER_ERA: u32 = 6;
+	/// The expected duration of an era in milliseconds.
+	pub const ERA_DURATION: Moment =
+		SESSIONS_PER_ERA as Moment * EPOCH_DURATION_IN_SLOTS * SLOT_DURATION;
+
 	// These time units are defined in number of blocks.
-	pub const MINUTES: BlockNumber = 60 / (SECS_PER_BLOCK as BlockNumber);
+	pub const MINUTES: BlockNumber = 60_000 / (MILLISECS_PER_BLOCK as BlockNumber);
 	pub const HOURS: BlockNumber = MINUTES * 60;
 	pub const DAYS: BlockNumber = HOURS * 24;
 }
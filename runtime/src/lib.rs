@@ -10,7 +10,7 @@
 #[cfg(feature = "std")]
 include!(concat!(env!("OUT_DIR"), "/wasm_binary.rs"));
 
-use scale_codec::{Decode, Encode};
+use scale_codec::{Decode, Encode, MaxEncodedLen};
 use sp_api::impl_runtime_apis;
 use sp_core::{crypto::{ByteArray, KeyTypeId}, OpaqueMetadata, H160, H256, U256};
 use sp_core_hashing::keccak_256;
@@ -20,10 +20,10 @@ use sp_arithmetic::{
 use pallet_session::historical as pallet_session_historical;
 use pallet_im_online::sr25519::AuthorityId as ImOnlineId;
 use sp_std::{marker::PhantomData, prelude::*};
-use sp_runtime::{create_runtime_str, curve::PiecewiseLinear, generic, impl_opaque_keys, traits::{
+use sp_runtime::{create_runtime_str, generic, impl_opaque_keys, traits::{
 	self, BlakeTwo256, Block as BlockT, Bounded, ConvertInto, NumberFor, OpaqueKeys,
 	SaturatedConversion, StaticLookup,
-}, transaction_validity::{TransactionPriority, TransactionSource, TransactionValidity}, ApplyExtrinsicResult, FixedPointNumber, FixedU128, Perbill, Percent, Permill, Perquintill, MultiSignature, ConsensusEngineId};
+}, transaction_validity::{TransactionPriority, TransactionSource, TransactionValidity}, ApplyExtrinsicResult, FixedPointNumber, FixedU128, Perbill, Percent, Permill, Perquintill, MultiSignature, ConsensusEngineId, RuntimeDebug};
 use sp_version::RuntimeVersion;
 use sp_runtime::transaction_validity::TransactionValidityError;
 // Substrate FRAME
@@ -52,7 +52,10 @@ use pallet_transaction_payment::CurrencyAdapter;
 // Frontier
 use fp_evm::weight_per_gas;
 use fp_rpc::TransactionStatus;
-use frame_support::traits::{EitherOfDiverse, U128CurrencyToVote};
+use frame_support::traits::{
+	Contains, EitherOfDiverse, EqualPrivilegeOnly, InstanceFilter, U128CurrencyToVote,
+	WithdrawReasons,
+};
 use pallet_ethereum::{Call::transact, PostLogContent, Transaction as EthereumTransaction};
 use pallet_evm::{
 	Account as EVMAccount, EnsureAddressTruncated, FeeCalculator, HashedAddressMapping, Runner, AddressMapping,
@@ -60,7 +63,7 @@ use pallet_evm::{
 
 // A few exports that help ease life for downstream crates.
 // pub use frame_system::Call as SystemCall;
-use frame_system::EnsureRoot;
+use frame_system::{EnsureRoot, EnsureSigned};
 use frame_system::offchain::Signer;
 // pub use pallet_balances::Call as BalancesCall;
 use frame_election_provider_support::{
@@ -81,6 +84,7 @@ use crate::const_evm_transaction::EVMConstFeeAdapter;
 
 mod voter_bags;
 mod address;
+mod timing;
 
 #[cfg(any(feature = "std", test))]
 use sp_version::NativeVersion;
@@ -90,9 +94,13 @@ pub use frame_system::Call as SystemCall;
 #[cfg(any(feature = "std", test))]
 pub use pallet_balances::Call as BalancesCall;
 #[cfg(any(feature = "std", test))]
-pub use pallet_staking::StakerStatus;
+pub use pallet_session::Call as SessionCall;
+#[cfg(any(feature = "std", test))]
+pub use pallet_staking::{Call as StakingCall, RewardDestination, StakerStatus, ValidatorPrefs};
 #[cfg(any(feature = "std", test))]
 pub use pallet_sudo::Call as SudoCall;
+#[cfg(any(feature = "std", test))]
+pub use pallet_utility::Call as UtilityCall;
 
 /// Alias to 512-bit hash when used in the context of a transaction signature on the chain.
 pub type Signature = MultiSignature;
@@ -153,17 +161,6 @@ impl pallet_session::historical::Config for Runtime {
 	type FullIdentificationOf = pallet_staking::ExposureOf<Runtime>;
 }
 
-pallet_staking_reward_curve::build! {
-	const REWARD_CURVE: PiecewiseLinear<'static> = curve!(
-		min_inflation: 0_025_000,
-		max_inflation: 0_100_000,
-		ideal_stake: 0_500_000,
-		falloff: 0_050_000,
-		max_piece_count: 40,
-		test_precision: 0_005_000,
-	);
-}
-
 impl pallet_utility::Config for Runtime {
 	type RuntimeEvent = RuntimeEvent;
 	type RuntimeCall = RuntimeCall;
@@ -172,16 +169,45 @@ impl pallet_utility::Config for Runtime {
 }
 
 parameter_types! {
-	pub const SessionsPerEra: sp_staking::SessionIndex = 6;
+	pub const SessionsPerEra: sp_staking::SessionIndex = SESSIONS_PER_ERA;
+	/// Unbonded funds can be withdrawn in the era they are unbonded. This is why the runtime has
+	/// no `pallet_fast_unstake`: nominators that were never exposed would not exit any faster.
 	pub const BondingDuration: sp_staking::EraIndex = 0;
-	pub const SlashDeferDuration: sp_staking::EraIndex = 24 * 7; // 1/4 the bonding duration.
-	pub const RewardCurve: &'static PiecewiseLinear<'static> = &REWARD_CURVE;
-	pub const MaxNominatorRewardedPerValidator: u32 = 256;
+	/// Slashes are applied, and the EVM fee rewards of their era can be paid out, this many eras
+	/// after the offence. This must stay below `HistoryDepth` for the fee rewards to be paid out
+	/// before they are pruned.
+	pub const SlashDeferDuration: sp_staking::EraIndex = 24 * 3;
+	/// The `SlashDeferDuration` the pending slashes were deferred by before v15 of the staking
+	/// storage.
+	pub const PreV15SlashDeferDuration: sp_staking::EraIndex = 24 * 7;
+	/// The inflation curve until the council sets another one with `Staking::set_inflation_params`.
+	pub const DefaultInflationParams: pallet_staking::InflationParams = pallet_staking::InflationParams {
+		min_inflation: Perbill::from_parts(25_000_000),
+		max_inflation: Perbill::from_percent(10),
+		ideal_stake: Perbill::from_percent(50),
+		falloff: Perbill::from_percent(5),
+	};
+	/// The yearly yield on stake above which EVM fee rewards replace inflation, which is the
+	/// yield of the default inflation curve at the ideal stake.
+	pub const TargetStakingYield: Perbill = Perbill::from_percent(20);
+	pub const MaxExposurePageSize: u32 = 256;
 	pub const OffendingValidatorsThreshold: Perbill = Perbill::from_percent(17);
 	pub OffchainRepeat: BlockNumber = 5;
 	pub HistoryDepth: u32 = 84;
 }
 
+/// Reports the EVM fee rewards paid out by staking through the events of `EvmFees`.
+pub struct ReportFeeRewards;
+impl pallet_staking::fees::OnFeeRewardPaid<AccountId, Balance> for ReportFeeRewards {
+	fn validator_fee_reward(validator: &AccountId, amount: Balance) {
+		EvmFees::validator_fee_reward(validator, amount)
+	}
+
+	fn nominator_fee_reward(nominator: &AccountId, validator: &AccountId, amount: Balance) {
+		EvmFees::nominator_fee_reward(nominator, validator, amount)
+	}
+}
+
 pub struct StakingBenchmarkingConfig;
 impl pallet_staking::BenchmarkingConfig for StakingBenchmarkingConfig {
 	type MaxNominators = ConstU32<1000>;
@@ -208,9 +234,13 @@ impl pallet_staking::Config for Runtime {
 		pallet_collective::EnsureProportionAtLeast<AccountId, CouncilCollective, 3, 4>,
 	>;
 	type SessionInterface = Self;
-	type EraPayout = pallet_staking::ConvertCurve<RewardCurve>;
+	type EraPayout = pallet_staking::FeeAwareEraPayout<
+		Runtime,
+		pallet_staking::InflationCurve<Runtime, DefaultInflationParams>,
+		TargetStakingYield,
+	>;
 	type NextNewSession = Session;
-	type MaxNominatorRewardedPerValidator = MaxNominatorRewardedPerValidator;
+	type MaxExposurePageSize = MaxExposurePageSize;
 	type OffendingValidatorsThreshold = OffendingValidatorsThreshold;
 	type ElectionProvider = ElectionProviderMultiPhase;
 	type GenesisElectionProvider = onchain::OnChainExecution<OnChainSeqPhragmen>;
@@ -220,9 +250,10 @@ impl pallet_staking::Config for Runtime {
 	type MaxUnlockingChunks = ConstU32<32>;
 	type HistoryDepth = HistoryDepth;
 	type OnStakerSlash = NominationPools;
+	type OnFeeRewardPaid = ReportFeeRewards;
 	type WeightInfo = pallet_staking::weights::SubstrateWeight<Runtime>;
 	type BenchmarkingConfig = StakingBenchmarkingConfig;
-	type AccountMapping = TruncateAccountMapping<BlakeTwo256>;
+	type AccountMapping = LinkedAccountMapping<Runtime, TruncateAccountMapping<BlakeTwo256>>;
 }
 
 
@@ -230,10 +261,10 @@ pub const VERSION: RuntimeVersion = RuntimeVersion {
 	spec_name: create_runtime_str!("node-frontier-template"),
 	impl_name: create_runtime_str!("node-frontier-template"),
 	authoring_version: 1,
-	spec_version: 1,
+	spec_version: 2,
 	impl_version: 1,
 	apis: RUNTIME_API_VERSIONS,
-	transaction_version: 1,
+	transaction_version: 2,
 	state_version: 1,
 };
 
@@ -243,15 +274,6 @@ pub const BABE_GENESIS_EPOCH_CONFIG: sp_consensus_babe::BabeEpochConfiguration =
 		allowed_slots: sp_consensus_babe::AllowedSlots::PrimaryAndSecondaryPlainSlots,
 	};
 
-pub const MILLISECS_PER_BLOCK: u64 = 6000;
-
-pub const SLOT_DURATION: u64 = MILLISECS_PER_BLOCK;
-
-// Time is measured by number of blocks.
-pub const MINUTES: BlockNumber = 60_000 / (MILLISECS_PER_BLOCK as BlockNumber);
-pub const HOURS: BlockNumber = MINUTES * 60;
-pub const DAYS: BlockNumber = HOURS * 24;
-
 /// The version information used to identify this runtime when compiled natively.
 #[cfg(feature = "std")]
 pub fn native_version() -> sp_version::NativeVersion {
@@ -516,7 +538,7 @@ use pallet_staking::NominatorsHandle;
 
 pub struct FindAuthorExtended<F, T: pallet_staking::Config + pallet_babe::Config + pallet_session::Config>(PhantomData<(F, T)>);
 impl<T, F: FindAuthor<u32>> FindAuthor<H160> for FindAuthorExtended<F,T> where
-	T: pallet_staking::Config + pallet_babe::Config + pallet_session::Config + frame_system::Config<AccountId = AccountId32>,
+	T: pallet_staking::Config + pallet_babe::Config + pallet_session::Config + pallet_account_mapping::Config + frame_system::Config<AccountId = AccountId32>,
 	AccountId32: From<<T as pallet_session::Config>::ValidatorId> {
 	fn find_author<'a, I>(digests: I) -> Option<H160>
 		where
@@ -527,12 +549,14 @@ impl<T, F: FindAuthor<u32>> FindAuthor<H160> for FindAuthorExtended<F,T> where
 
 			let authorities = <pallet_session::Pallet<T>>::validators();
 			log::info!("Validators: {:?}", authorities);
-			let authority_id = authorities[author_index as usize].clone();
-			let validator_id = authority_id.clone().into();
-
-			if let Some(key) = pallet_staking::Bonded::<T>::iter().find(|key| key.0.eq(&validator_id)) {
-				let key = key.1;
-				return Some(H160::from_slice(&<AccountId32 as AsRef<[u8;32]>>::as_ref(&key)[12..]));
+			let authority_id = authorities.get(author_index as usize)?.clone();
+			let stash: AccountId32 = authority_id.into();
+
+			// Session validators are stashes, the author address is the one of the controller.
+			if let Some(key) = pallet_staking::Bonded::<T>::get(&stash) {
+				return Some(pallet_account_mapping::Pallet::<T>::evm_address(&key).unwrap_or_else(|| {
+					H160::from_slice(&<AccountId32 as AsRef<[u8;32]>>::as_ref(&key)[12..])
+				}));
 			}
 		}
 		None
@@ -557,6 +581,31 @@ impl AddressMapping<AccountId32> for ExtendedAddressMapping {
 	}
 }
 
+parameter_types! {
+	pub const AccountClaimPrefix: &'static [u8] = b"Link QChain account:";
+}
+
+/// Stashes, controllers, pool members and accounts with a vesting schedule, whose funds cannot
+/// be moved to the native account linked to their EVM address.
+pub struct StakedOrVesting;
+
+impl Contains<AccountId> for StakedOrVesting {
+	fn contains(who: &AccountId) -> bool {
+		pallet_staking::Bonded::<Runtime>::contains_key(who) ||
+			pallet_staking::Ledger::<Runtime>::contains_key(who) ||
+			pallet_nomination_pools::PoolMembers::<Runtime>::contains_key(who) ||
+			pallet_vesting::Vesting::<Runtime>::contains_key(who)
+	}
+}
+
+impl pallet_account_mapping::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type Currency = Balances;
+	type FallbackMapping = ExtendedAddressMapping;
+	type ClaimPrefix = AccountClaimPrefix;
+	type StakedOrVesting = StakedOrVesting;
+}
+
 parameter_types! {
 	pub BlockGasLimit: U256 = U256::from(BLOCK_GAS_LIMIT);
 	pub PrecompilesValue: FrontierPrecompiles<Runtime> = FrontierPrecompiles::<_>::new();
@@ -568,9 +617,9 @@ impl pallet_evm::Config for Runtime {
 	type GasWeightMapping = pallet_evm::FixedGasWeightMapping<Self>;
 	type WeightPerGas = WeightPerGas;
 	type BlockHashMapping = pallet_ethereum::EthereumBlockHashMapping<Self>;
-	type CallOrigin = EnsureAddressTruncated;
-	type WithdrawOrigin = EnsureAddressTruncated;
-	type AddressMapping = ExtendedAddressMapping;
+	type CallOrigin = EnsureAddressLinked<Self, EnsureAddressTruncated>;
+	type WithdrawOrigin = EnsureAddressLinked<Self, EnsureAddressTruncated>;
+	type AddressMapping = LinkedAddressMapping<Self>;
 	type Currency = Balances;
 	type RuntimeEvent = RuntimeEvent;
 	type PrecompilesType = FrontierPrecompiles<Self>;
@@ -593,10 +642,19 @@ impl pallet_ethereum::Config for Runtime {
 	type PostLogContent = PostBlockAndTxnHashes;
 	type Staking = Staking;
 	type SetKeys = Session;
-	type AddressMapping = ExtendedAddressMapping;
+	type AddressMapping = LinkedAddressMapping<Self>;
 	type Currency = Balances;
 }
 
+parameter_types! {
+	pub EvmFeesLogAddress: H160 = H160::from_low_u64_be(2048);
+}
+
+impl pallet_evm_fees::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type LogAddress = EvmFeesLogAddress;
+}
+
 parameter_types! {
 	pub BoundDivision: U256 = U256::from(1024);
 }
@@ -905,6 +963,313 @@ impl pallet_collective::Config<CouncilCollective> for Runtime {
 	type SetMembersOrigin = EnsureRoot<Self::AccountId>;
 }
 
+parameter_types! {
+	pub MaximumSchedulerWeight: Weight = Perbill::from_percent(80) *
+		RuntimeBlockWeights::get().max_block;
+}
+
+impl pallet_scheduler::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type RuntimeOrigin = RuntimeOrigin;
+	type PalletsOrigin = OriginCaller;
+	type RuntimeCall = RuntimeCall;
+	type MaximumWeight = MaximumSchedulerWeight;
+	type ScheduleOrigin = EnsureRoot<AccountId>;
+	type MaxScheduledPerBlock = ConstU32<512>;
+	type WeightInfo = pallet_scheduler::weights::SubstrateWeight<Runtime>;
+	type OriginPrivilegeCmp = EqualPrivilegeOnly;
+	type Preimages = Preimage;
+}
+
+parameter_types! {
+	pub const PreimageBaseDeposit: Balance = 1 * DOLLARS;
+	pub const PreimageByteDeposit: Balance = 1 * CENTS;
+}
+
+impl pallet_preimage::Config for Runtime {
+	type WeightInfo = pallet_preimage::weights::SubstrateWeight<Runtime>;
+	type RuntimeEvent = RuntimeEvent;
+	type Currency = Balances;
+	type ManagerOrigin = EnsureRoot<AccountId>;
+	type BaseDeposit = PreimageBaseDeposit;
+	type ByteDeposit = PreimageByteDeposit;
+}
+
+parameter_types! {
+	pub const LaunchPeriod: BlockNumber = 7 * DAYS;
+	pub const VotingPeriod: BlockNumber = 7 * DAYS;
+	pub const FastTrackVotingPeriod: BlockNumber = 3 * HOURS;
+	pub const MinimumDeposit: Balance = 100 * DOLLARS;
+	pub const EnactmentPeriod: BlockNumber = 8 * DAYS;
+	pub const CooloffPeriod: BlockNumber = 7 * DAYS;
+	pub const MaxProposals: u32 = 100;
+}
+
+/// Referenda pass as `Root`, so the public and the Council can enact runtime upgrades
+/// (`set_code`), staking configuration and fee parameters without the sudo key.
+impl pallet_democracy::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type Currency = Balances;
+	type EnactmentPeriod = EnactmentPeriod;
+	type LaunchPeriod = LaunchPeriod;
+	type VotingPeriod = VotingPeriod;
+	type VoteLockingPeriod = EnactmentPeriod; // Same as EnactmentPeriod
+	type MinimumDeposit = MinimumDeposit;
+	/// A straight majority of the council can decide what their next motion is.
+	type ExternalOrigin =
+		pallet_collective::EnsureProportionAtLeast<AccountId, CouncilCollective, 1, 2>;
+	/// A super-majority can have the next scheduled referendum be a straight majority-carries vote.
+	type ExternalMajorityOrigin =
+		pallet_collective::EnsureProportionAtLeast<AccountId, CouncilCollective, 3, 4>;
+	/// A unanimous council can have the next scheduled referendum be a straight default-carries
+	/// (NTB) vote.
+	type ExternalDefaultOrigin =
+		pallet_collective::EnsureProportionAtLeast<AccountId, CouncilCollective, 1, 1>;
+	type SubmitOrigin = EnsureSigned<AccountId>;
+	/// Two thirds of the council can have an ExternalMajority/ExternalDefault vote
+	/// be tabled immediately and with a shorter voting/enactment period.
+	type FastTrackOrigin =
+		pallet_collective::EnsureProportionAtLeast<AccountId, CouncilCollective, 2, 3>;
+	type InstantOrigin =
+		pallet_collective::EnsureProportionAtLeast<AccountId, CouncilCollective, 1, 1>;
+	type InstantAllowed = frame_support::traits::ConstBool<true>;
+	type FastTrackVotingPeriod = FastTrackVotingPeriod;
+	// To cancel a proposal which has been passed, 2/3 of the council must agree to it.
+	type CancellationOrigin =
+		pallet_collective::EnsureProportionAtLeast<AccountId, CouncilCollective, 2, 3>;
+	// To cancel a proposal before it has been passed, the council must be unanimous or
+	// Root must agree.
+	type CancelProposalOrigin = EitherOfDiverse<
+		EnsureRoot<AccountId>,
+		pallet_collective::EnsureProportionAtLeast<AccountId, CouncilCollective, 1, 1>,
+	>;
+	type BlacklistOrigin = EnsureRoot<AccountId>;
+	// Any single council member may veto a coming council proposal, however they can
+	// only do it once and it lasts only for the cool-off period.
+	type VetoOrigin = pallet_collective::EnsureMember<AccountId, CouncilCollective>;
+	type CooloffPeriod = CooloffPeriod;
+	type Slash = Treasury;
+	type Scheduler = Scheduler;
+	type PalletsOrigin = OriginCaller;
+	type MaxVotes = ConstU32<100>;
+	type WeightInfo = pallet_democracy::weights::SubstrateWeight<Runtime>;
+	type MaxProposals = MaxProposals;
+	type Preimages = Preimage;
+	type MaxDeposits = ConstU32<100>;
+	type MaxBlacklisted = ConstU32<100>;
+}
+
+parameter_types! {
+	// One storage item; key size is 32; value is size 4+4+16+32 bytes = 56 bytes.
+	pub const DepositBase: Balance = deposit(1, 88);
+	// Additional storage item size of 32 bytes.
+	pub const DepositFactor: Balance = deposit(0, 32);
+}
+
+impl pallet_multisig::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type RuntimeCall = RuntimeCall;
+	type Currency = Balances;
+	type DepositBase = DepositBase;
+	type DepositFactor = DepositFactor;
+	type MaxSignatories = ConstU32<100>;
+	type WeightInfo = pallet_multisig::weights::SubstrateWeight<Runtime>;
+}
+
+parameter_types! {
+	// One storage item; key size 32, value size 8.
+	pub const ProxyDepositBase: Balance = deposit(1, 8);
+	// Additional storage item size of 33 bytes.
+	pub const ProxyDepositFactor: Balance = deposit(0, 33);
+	pub const AnnouncementDepositBase: Balance = deposit(1, 8);
+	pub const AnnouncementDepositFactor: Balance = deposit(0, 66);
+}
+
+/// The type used to represent the kinds of proxying allowed.
+#[derive(
+	Copy,
+	Clone,
+	Eq,
+	PartialEq,
+	Ord,
+	PartialOrd,
+	Encode,
+	Decode,
+	RuntimeDebug,
+	MaxEncodedLen,
+	scale_info::TypeInfo,
+)]
+pub enum ProxyType {
+	Any,
+	/// Anything but moving funds, natively or through the EVM.
+	NonTransfer,
+	/// Bonding, nominating and validating, directly or through nomination pools.
+	Staking,
+	/// Council, referenda and treasury spending.
+	Governance,
+}
+
+impl Default for ProxyType {
+	fn default() -> Self {
+		Self::Any
+	}
+}
+
+impl InstanceFilter<RuntimeCall> for ProxyType {
+	fn filter(&self, c: &RuntimeCall) -> bool {
+		match self {
+			ProxyType::Any => true,
+			ProxyType::NonTransfer => !matches!(
+				c,
+				RuntimeCall::Balances(..) |
+					RuntimeCall::Vesting(pallet_vesting::Call::vested_transfer { .. }) |
+					RuntimeCall::EVM(..) |
+					RuntimeCall::Ethereum(..) |
+					RuntimeCall::AccountMapping(..)
+			),
+			ProxyType::Staking => matches!(
+				c,
+				RuntimeCall::Staking(..) |
+					RuntimeCall::Session(..) |
+					RuntimeCall::Utility(..) |
+					RuntimeCall::VoterList(..) |
+					RuntimeCall::NominationPools(..)
+			),
+			ProxyType::Governance => matches!(
+				c,
+				RuntimeCall::Democracy(..) |
+					RuntimeCall::Council(..) |
+					RuntimeCall::Treasury(..) |
+					RuntimeCall::Bounties(..) |
+					RuntimeCall::ChildBounties(..) |
+					RuntimeCall::Utility(..)
+			),
+		}
+	}
+
+	fn is_superset(&self, o: &Self) -> bool {
+		match (self, o) {
+			(x, y) if x == y => true,
+			(ProxyType::Any, _) => true,
+			(_, ProxyType::Any) => false,
+			(ProxyType::NonTransfer, _) => true,
+			_ => false,
+		}
+	}
+}
+
+impl pallet_proxy::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type RuntimeCall = RuntimeCall;
+	type Currency = Balances;
+	type ProxyType = ProxyType;
+	type ProxyDepositBase = ProxyDepositBase;
+	type ProxyDepositFactor = ProxyDepositFactor;
+	type MaxProxies = ConstU32<32>;
+	type WeightInfo = pallet_proxy::weights::SubstrateWeight<Runtime>;
+	type MaxPending = ConstU32<32>;
+	type CallHasher = BlakeTwo256;
+	type AnnouncementDepositBase = AnnouncementDepositBase;
+	type AnnouncementDepositFactor = AnnouncementDepositFactor;
+}
+
+parameter_types! {
+	pub const BasicDeposit: Balance = 10 * DOLLARS;       // 258 bytes on-chain
+	pub const FieldDeposit: Balance = 250 * CENTS;        // 66 bytes on-chain
+	pub const SubAccountDeposit: Balance = 2 * DOLLARS;   // 53 bytes on-chain
+	pub const MaxSubAccounts: u32 = 100;
+	pub const MaxAdditionalFields: u32 = 100;
+	pub const MaxRegistrars: u32 = 20;
+}
+
+/// Registrars are appointed by the Council, so that validator names shown to nominators carry
+/// judgements from parties the chain trusts.
+impl pallet_identity::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type Currency = Balances;
+	type BasicDeposit = BasicDeposit;
+	type FieldDeposit = FieldDeposit;
+	type SubAccountDeposit = SubAccountDeposit;
+	type MaxSubAccounts = MaxSubAccounts;
+	type MaxAdditionalFields = MaxAdditionalFields;
+	type MaxRegistrars = MaxRegistrars;
+	type Slashed = Treasury;
+	type ForceOrigin = EnsureRootOrHalfCouncil;
+	type RegistrarOrigin = EnsureRootOrHalfCouncil;
+	type WeightInfo = pallet_identity::weights::SubstrateWeight<Runtime>;
+}
+
+/// The timing of the chain, as configured in BABE, the timestamp and staking pallets.
+fn chain_timing() -> pallet_staking_runtime_api::Timing {
+	let slot_duration = Babe::slot_duration();
+	let epoch_duration = EpochDuration::get();
+	let session_duration = epoch_duration * slot_duration;
+	pallet_staking_runtime_api::Timing {
+		block_time: ExpectedBlockTime::get(),
+		slot_duration,
+		epoch_duration,
+		session_duration,
+		sessions_per_era: SessionsPerEra::get(),
+		era_duration: SessionsPerEra::get() as u64 * session_duration,
+	}
+}
+
+/// The identity of `validator` for the staking runtime API. A sub-account is shown under the
+/// name of its parent identity.
+fn validator_identity(validator: AccountId) -> pallet_staking_runtime_api::ValidatorIdentity<AccountId> {
+	use pallet_identity::{Data, Judgement};
+	use pallet_staking_runtime_api::Judgement as ApiJudgement;
+
+	fn raw(data: &Data) -> Option<Vec<u8>> {
+		match data {
+			Data::Raw(bytes) => Some(bytes.to_vec()),
+			_ => None,
+		}
+	}
+
+	let (registration, display) = match Identity::super_of(&validator) {
+		Some((parent, sub_name)) => {
+			let registration = Identity::identity(&parent);
+			let display = registration.as_ref().and_then(|r| raw(&r.info.display)).map(|mut name| {
+				if let Some(sub_name) = raw(&sub_name) {
+					name.push(b'/');
+					name.extend(sub_name);
+				}
+				name
+			});
+			(registration, display)
+		},
+		None => {
+			let registration = Identity::identity(&validator);
+			let display = registration.as_ref().and_then(|r| raw(&r.info.display));
+			(registration, display)
+		},
+	};
+	let judgements = registration
+		.map(|r| {
+			r.judgements
+				.iter()
+				.map(|(registrar, judgement)| {
+					let judgement = match judgement {
+						Judgement::Unknown => ApiJudgement::Unknown,
+						Judgement::FeePaid(_) => ApiJudgement::FeePaid,
+						Judgement::Reasonable => ApiJudgement::Reasonable,
+						Judgement::KnownGood => ApiJudgement::KnownGood,
+						Judgement::OutOfDate => ApiJudgement::OutOfDate,
+						Judgement::LowQuality => ApiJudgement::LowQuality,
+						Judgement::Erroneous => ApiJudgement::Erroneous,
+					};
+					(*registrar, judgement)
+				})
+				.collect()
+		})
+		.unwrap_or_default();
+
+	pallet_staking_runtime_api::ValidatorIdentity { validator, display, judgements }
+}
+
+impl timing::pallet::Config for Runtime {}
+
 impl<C> frame_system::offchain::SendTransactionTypes<C> for Runtime
 	where
 		RuntimeCall: From<C>,
@@ -921,7 +1286,8 @@ parameter_types! {
 }
 
 use sp_runtime::traits::Convert;
-use crate::address::EnsureAddressHashing;
+use crate::address::{EnsureAddressHashing, LinkedAccountMapping};
+use pallet_account_mapping::{EnsureAddressLinked, LinkedAddressMapping};
 
 pub struct BalanceToU256;
 impl Convert<Balance, sp_core::U256> for BalanceToU256 {
@@ -988,6 +1354,24 @@ impl pallet_child_bounties::Config for Runtime {
 	type WeightInfo = pallet_child_bounties::weights::SubstrateWeight<Runtime>;
 }
 
+parameter_types! {
+	pub const MinVestedTransfer: Balance = 100 * DOLLARS;
+	pub UnvestedFundsAllowedWithdrawReasons: WithdrawReasons =
+		WithdrawReasons::except(WithdrawReasons::TRANSFER | WithdrawReasons::RESERVE);
+}
+
+impl pallet_vesting::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type Currency = Balances;
+	type BlockNumberToBalance = ConvertInto;
+	type MinVestedTransfer = MinVestedTransfer;
+	type WeightInfo = pallet_vesting::weights::SubstrateWeight<Runtime>;
+	type UnvestedFundsAllowedWithdrawReasons = UnvestedFundsAllowedWithdrawReasons;
+	// `VestingInfo` encode length is 36bytes. 28 schedules gets encoded as 1009 bytes, which is the
+	// highest number of schedules that encodes less than 2^10.
+	const MAX_VESTING_SCHEDULES: u32 = 28;
+}
+
 // Create the runtime by composing the FRAME pallets that were previously configured.
 construct_runtime!(
 	pub enum Runtime where
@@ -1023,6 +1407,17 @@ construct_runtime!(
 		Council: pallet_collective::<Instance1>,
 		Bounties: pallet_bounties,
 		ChildBounties: pallet_child_bounties,
+		EvmFees: pallet_evm_fees,
+		AccountMapping: pallet_account_mapping,
+		// After `EVM`, so that genesis EVM allocations can be vested.
+		Vesting: pallet_vesting,
+		Scheduler: pallet_scheduler,
+		Preimage: pallet_preimage,
+		Democracy: pallet_democracy,
+		Multisig: pallet_multisig,
+		Proxy: pallet_proxy,
+		Identity: pallet_identity,
+		Timing: timing::pallet,
 
 		// Mmr: pallet_mmr,
 	}
@@ -1089,63 +1484,17 @@ pub type Executive = frame_executive::Executive<
 	frame_system::ChainContext<Runtime>,
 	Runtime,
 	AllPalletsWithSystem,
-	account_migration::Upgrade
+	Migrations,
 >;
 
-mod account_migration {
-	use frame_support::traits::{ExistenceRequirement, OnRuntimeUpgrade, Currency};
-	use frame_system::Account;
-	use sp_staking::StakingInterface;
-	use super::*;
-
-	pub struct Upgrade;
-	impl OnRuntimeUpgrade for Upgrade {
-		fn on_runtime_upgrade() -> Weight {
-			let old_version = frame_support::traits::StorageVersion::get::<frame_system::Pallet<Runtime>>();
-			log::info!("Old storage version: {:?}", old_version);
-			frame_support::traits::StorageVersion::new(1).put::<frame_system::Pallet<Runtime>>();
-			let new_version = frame_support::traits::StorageVersion::get::<frame_system::Pallet<Runtime>>();
-			log::info!("New storage version: {:?}", new_version);
-			let accounts = vec![
-				(AccountId32::from(hex_literal::hex!["90b5ab205c6974c9ea841be688864633dc9ca8a357843eeacf2314649965fe22"]),
-				 AccountId32::from(hex_literal::hex!["306721211d5404bd9da88e0204360a1a9ab8b87c66c1bc2fcdd37f3c2222cc20"])),
-			];
-
-			for account in accounts {
-
-				if let Err(err) = <Staking as StakingInterface>::chill(&account.0)  {
-					log::error!("Error while chill: {:?}", err);
-				}
-				if let Err(err) = <Staking as StakingInterface>::force_unstake(account.0.clone()) {
-					log::error!("Error force unstake: {:?}", err);
-				}
-
-				log::info!("Migrated from: {:?} to: {:?}", &account.0, &account.1);
-				log::info!("Total balance before:");
-				log::info!("Account: {:?}, Balance: {:?}", account.0, Balances::total_balance(&account.0));
-				log::info!("Account: {:?}, Balance: {:?}", account.1, Balances::total_balance(&account.1));
-
-				let can_provided = System::can_dec_provider(&account.0);
-				let system_account = Account::<Runtime>::get(&account.0);
-
-				log::info!("Account provider {}, consumer {}, can provided: {}", system_account.providers, system_account.consumers, can_provided);
-
-				let transferred_balance = if can_provided {
-					Balances::total_balance(&account.0)
-				} else {
-					Balances::total_balance(&account.0) - MIN_BALANCE
-				};
-
-				if let Err(err) = <Balances as Currency<_>>::transfer(&account.0, &account.1, transferred_balance, ExistenceRequirement::AllowDeath) {
-					log::error!("Error while migration transfer: {:?}", err);
-				}
-				log::info!("Account: {:?}, Balance: {:?}", account.0, Balances::total_balance(&account.0));
-				log::info!("Account: {:?}, Balance: {:?}", account.1, Balances::total_balance(&account.1));
-			}
-			Weight::zero()
-		}
-	}
-}
+/// Runtime migrations, executed in order.
+///
+/// Accounts whose keys were lost are moved by the council with `Staking::move_account` rather than
+/// by a migration.
+type Migrations = (
+	pallet_staking::migrations::v14::MigrateToV14<Runtime>,
+	pallet_staking::migrations::v15::MigrateToV15<Runtime, PreV15SlashDeferDuration>,
+);
 
 impl fp_self_contained::SelfContainedCall for RuntimeCall {
 	type SignedInfo = H160;
@@ -1484,6 +1833,62 @@ impl_runtime_apis! {
 		fn gas_limit_multiplier_support() {}
 	}
 
+	impl pallet_staking_runtime_api::StakingApi<Block, Balance, AccountId> for Runtime {
+		fn nominations_quota(balance: Balance) -> u32 {
+			Staking::api_nominations_quota(balance)
+		}
+
+		fn eras_fee_rewards() -> Vec<(sp_staking::EraIndex, Balance)> {
+			Staking::api_eras_fee_rewards()
+		}
+
+		fn validator_fee_rewards(validator: AccountId) -> Vec<(sp_staking::EraIndex, Balance)> {
+			Staking::api_validator_fee_rewards(validator)
+		}
+
+		fn validator_identities() -> Vec<pallet_staking_runtime_api::ValidatorIdentity<AccountId>> {
+			pallet_staking::Validators::<Runtime>::iter_keys().map(validator_identity).collect()
+		}
+
+		fn era_payout(staking_ratio: Perbill) -> (Balance, Balance) {
+			Staking::api_era_payout(staking_ratio, chain_timing().era_duration)
+		}
+
+		fn timing() -> pallet_staking_runtime_api::Timing {
+			chain_timing()
+		}
+
+		fn unapplied_slashes() -> Vec<pallet_staking_runtime_api::UnappliedSlash<AccountId, Balance>> {
+			Staking::api_unapplied_slashes()
+				.into_iter()
+				.flat_map(|(apply_era, slash_era, slashes)| {
+					slashes.into_iter().enumerate().map(move |(index, (slash, fee_rewards))| {
+						pallet_staking_runtime_api::UnappliedSlash {
+							apply_era,
+							index: index as u32,
+							slash_era,
+							validator: slash.validator,
+							own: slash.own,
+							others: slash.others,
+							payout: slash.payout,
+							fee_rewards,
+						}
+					})
+				})
+				.collect()
+		}
+	}
+
+	impl pallet_account_mapping_runtime_api::AccountMappingApi<Block, AccountId> for Runtime {
+		fn native_account(address: H160) -> (AccountId, bool) {
+			(AccountMapping::account_of(address), AccountMapping::native_account(address).is_some())
+		}
+
+		fn evm_address(account: AccountId) -> Option<H160> {
+			AccountMapping::evm_address(account)
+		}
+	}
+
 	impl fp_rpc::ConvertTransactionRuntimeApi<Block> for Runtime {
 		fn convert_transaction(transaction: EthereumTransaction) -> <Block as BlockT>::Extrinsic {
 			UncheckedExtrinsic::new_unsigned(
@@ -1561,6 +1966,27 @@ impl_runtime_apis! {
 		}
 	}
 
+	#[cfg(feature = "try-runtime")]
+	impl frame_try_runtime::TryRuntime<Block> for Runtime {
+		fn on_runtime_upgrade(checks: bool) -> (Weight, Weight) {
+			// NOTE: intentional unwrap: we don't want to propagate the error backwards, and want to
+			// have a backtrace here. If any of the pre/post migration checks fail, we shall stop
+			// right here and right now.
+			let weight = Executive::try_runtime_upgrade(checks).unwrap();
+			(weight, RuntimeBlockWeights::get().max_block)
+		}
+
+		fn execute_block(
+			block: Block,
+			state_root_check: bool,
+			select: frame_try_runtime::TryStateSelect
+		) -> Weight {
+			// NOTE: intentional unwrap: we don't want to propagate the error backwards, and want to
+			// have a backtrace here.
+			Executive::try_execute_block(block, state_root_check, select).expect("execute-block failed")
+		}
+	}
+
 	#[cfg(feature = "runtime-benchmarks")]
 	impl frame_benchmarking::Benchmark<Block> for Runtime {
 		fn benchmark_metadata(extra: bool) -> (
@@ -1603,7 +2029,7 @@ impl_runtime_apis! {
 
 #[cfg(test)]
 mod tests {
-	use super::{Runtime, WeightPerGas};
+	use super::*;
 	#[test]
 	fn configured_base_extrinsic_weight_is_evm_compatible() {
 		let min_ethereum_transaction_weight = WeightPerGas::get() * 21_000;
@@ -1,11 +1,22 @@
+use frame_support::dispatch::{Dispatchable, GetDispatchInfo, PostDispatchInfo};
+use frame_system::RawOrigin;
 use pallet_evm::{Precompile, PrecompileHandle, PrecompileResult, PrecompileSet};
 use sp_core::H160;
 use sp_std::marker::PhantomData;
 
+use pallet_account_mapping::AccountMappingPrecompile;
 use pallet_evm_precompile_modexp::Modexp;
 use pallet_evm_precompile_sha3fips::Sha3FIPS256;
 use pallet_evm_precompile_simple::{ECRecover, ECRecoverPublicKey, Identity, Ripemd160, Sha256};
 
+mod multisig;
+mod proxy;
+mod utils;
+mod vesting;
+use multisig::MultisigPrecompile;
+use proxy::ProxyPrecompile;
+use vesting::VestingPrecompile;
+
 pub struct FrontierPrecompiles<R>(PhantomData<R>);
 
 impl<R> FrontierPrecompiles<R>
@@ -15,7 +26,7 @@ where
 	pub fn new() -> Self {
 		Self(Default::default())
 	}
-	pub fn used_addresses() -> [H160; 7] {
+	pub fn used_addresses() -> [H160; 11] {
 		[
 			hash(1),
 			hash(2),
@@ -24,12 +35,31 @@ where
 			hash(5),
 			hash(1024),
 			hash(1025),
+			hash(2049),
+			hash(2050),
+			hash(2051),
+			hash(2052),
 		]
 	}
 }
 impl<R> PrecompileSet for FrontierPrecompiles<R>
 where
-	R: pallet_evm::Config,
+	R: pallet_evm::Config
+		+ pallet_account_mapping::Config
+		+ pallet_vesting::Config
+		+ pallet_balances::Config
+		+ pallet_multisig::Config
+		+ pallet_proxy::Config,
+	R::AccountId: From<[u8; 32]> + Ord,
+	R::BlockNumber: From<u32>,
+	<R as frame_system::Config>::RuntimeCall: Dispatchable<PostInfo = PostDispatchInfo>
+		+ GetDispatchInfo
+		+ From<pallet_multisig::Call<R>>
+		+ From<pallet_proxy::Call<R>>,
+	<<R as frame_system::Config>::RuntimeCall as Dispatchable>::RuntimeOrigin:
+		From<RawOrigin<R::AccountId>>,
+	<R as pallet_multisig::Config>::RuntimeCall: scale_codec::Decode + GetDispatchInfo,
+	<R as pallet_proxy::Config>::RuntimeCall: scale_codec::Decode,
 {
 	fn execute(&self, handle: &mut impl PrecompileHandle) -> Option<PrecompileResult> {
 		match handle.code_address() {
@@ -42,6 +72,11 @@ where
 			// Non-Frontier specific nor Ethereum precompiles :
 			a if a == hash(1024) => Some(Sha3FIPS256::execute(handle)),
 			a if a == hash(1025) => Some(ECRecoverPublicKey::execute(handle)),
+			// QChain precompiles :
+			a if a == hash(2049) => Some(AccountMappingPrecompile::<R>::execute(handle)),
+			a if a == hash(2050) => Some(VestingPrecompile::<R>::execute(handle)),
+			a if a == hash(2051) => Some(MultisigPrecompile::<R>::execute(handle)),
+			a if a == hash(2052) => Some(ProxyPrecompile::<R>::execute(handle)),
 			_ => None,
 		}
 	}
@@ -0,0 +1,122 @@
+//! Access to `pallet_multisig` for EVM accounts, following the Solidity ABI of
+//!
+//! ```solidity
+//! interface Multisig {
+//!     // Approve `call`, a SCALE encoded runtime call, and dispatch it from the multisig
+//!     // account if this is the last approval needed.
+//!     function asMulti(
+//!         uint16 threshold,
+//!         bytes32[] otherSignatories,
+//!         uint32 timepointHeight,
+//!         uint32 timepointIndex,
+//!         bytes call
+//!     ) external;
+//!     // Approve the call with the given blake2-256 hash without dispatching it.
+//!     function approveAsMulti(
+//!         uint16 threshold,
+//!         bytes32[] otherSignatories,
+//!         uint32 timepointHeight,
+//!         uint32 timepointIndex,
+//!         bytes32 callHash
+//!     ) external;
+//!     // Cancel a pending call opened by the caller.
+//!     function cancelAsMulti(
+//!         uint16 threshold,
+//!         bytes32[] otherSignatories,
+//!         uint32 timepointHeight,
+//!         uint32 timepointIndex,
+//!         bytes32 callHash
+//!     ) external;
+//! }
+//! ```
+//!
+//! Signatories may be given in any order and may include the caller. As with the dispatchables,
+//! every approval but the first one gives the timepoint of the first one: the block number and
+//! extrinsic index of the `NewMultisig` event. The first approval gives a zero timepoint.
+
+use scale_codec::Decode;
+use frame_support::dispatch::{Dispatchable, GetDispatchInfo, PostDispatchInfo};
+use frame_system::RawOrigin;
+use pallet_evm::{AddressMapping, Precompile, PrecompileFailure, PrecompileHandle, PrecompileResult};
+use pallet_multisig::Timepoint;
+use sp_std::{marker::PhantomData, prelude::*};
+
+use super::utils::{decode_call, dispatch, revert, Reader};
+
+/// `bytes4(keccak256("asMulti(uint16,bytes32[],uint32,uint32,bytes)"))`
+pub const AS_MULTI_SELECTOR: [u8; 4] = [0x83, 0xab, 0x3c, 0x12];
+/// `bytes4(keccak256("approveAsMulti(uint16,bytes32[],uint32,uint32,bytes32)"))`
+pub const APPROVE_AS_MULTI_SELECTOR: [u8; 4] = [0x79, 0x57, 0xa7, 0xcd];
+/// `bytes4(keccak256("cancelAsMulti(uint16,bytes32[],uint32,uint32,bytes32)"))`
+pub const CANCEL_AS_MULTI_SELECTOR: [u8; 4] = [0x5e, 0x2c, 0xff, 0x6c];
+
+pub struct MultisigPrecompile<R>(PhantomData<R>);
+
+impl<R> Precompile for MultisigPrecompile<R>
+where
+	R: pallet_evm::Config + pallet_multisig::Config,
+	R::AccountId: From<[u8; 32]> + Ord,
+	R::BlockNumber: From<u32>,
+	<R as frame_system::Config>::RuntimeCall: Dispatchable<PostInfo = PostDispatchInfo>
+		+ GetDispatchInfo
+		+ From<pallet_multisig::Call<R>>,
+	<<R as frame_system::Config>::RuntimeCall as Dispatchable>::RuntimeOrigin:
+		From<RawOrigin<R::AccountId>>,
+	<R as pallet_multisig::Config>::RuntimeCall: Decode + GetDispatchInfo,
+{
+	fn execute(handle: &mut impl PrecompileHandle) -> PrecompileResult {
+		let who = R::AddressMapping::into_account_id(handle.context().caller);
+		let input = handle.input().to_vec();
+		let (selector, args) = Reader::new(&input)?;
+
+		let threshold = u16::try_from(args.u32(0)?).map_err(|_| revert("threshold out of range"))?;
+		let mut other_signatories =
+			args.bytes32_array(1)?.into_iter().map(R::AccountId::from).collect::<Vec<_>>();
+		other_signatories.retain(|signatory| signatory != &who);
+		other_signatories.sort();
+		other_signatories.dedup();
+		let maybe_timepoint = Self::timepoint(&args)?;
+
+		let call = match selector {
+			AS_MULTI_SELECTOR => {
+				let call: <R as pallet_multisig::Config>::RuntimeCall =
+					decode_call(&args.bytes(4)?)?;
+				pallet_multisig::Call::<R>::as_multi {
+					threshold,
+					other_signatories,
+					maybe_timepoint,
+					max_weight: call.get_dispatch_info().weight,
+					call: Box::new(call),
+				}
+			},
+			APPROVE_AS_MULTI_SELECTOR => pallet_multisig::Call::<R>::approve_as_multi {
+				threshold,
+				other_signatories,
+				maybe_timepoint,
+				call_hash: args.bytes32(4)?,
+				max_weight: Default::default(),
+			},
+			CANCEL_AS_MULTI_SELECTOR => pallet_multisig::Call::<R>::cancel_as_multi {
+				threshold,
+				other_signatories,
+				timepoint: maybe_timepoint.ok_or_else(|| revert("no timepoint"))?,
+				call_hash: args.bytes32(4)?,
+			},
+			_ => return Err(revert("invalid selector")),
+		};
+
+		dispatch::<R>(handle, who, call)
+	}
+}
+
+impl<R> MultisigPrecompile<R>
+where
+	R: pallet_multisig::Config,
+	R::BlockNumber: From<u32>,
+{
+	/// The timepoint given as the third and fourth arguments, `None` if zero.
+	fn timepoint(args: &Reader) -> Result<Option<Timepoint<R::BlockNumber>>, PrecompileFailure> {
+		let (height, index) = (args.u32(2)?, args.u32(3)?);
+		Ok((height != 0).then(|| Timepoint { height: height.into(), index }))
+	}
+}
@@ -0,0 +1,80 @@
+//! Access to `pallet_proxy` for EVM accounts, following the Solidity ABI of
+//!
+//! ```solidity
+//! interface Proxy {
+//!     // Dispatch `call`, a SCALE encoded runtime call, on behalf of `real`, which registered
+//!     // the caller as one of its proxies.
+//!     function proxy(bytes32 real, bytes call) external;
+//!     // Register `delegate` as a proxy of the caller, `proxyType` being the index of the
+//!     // runtime `ProxyType` variant.
+//!     function addProxy(bytes32 delegate, uint8 proxyType, uint32 delay) external;
+//!     // Unregister a proxy of the caller.
+//!     function removeProxy(bytes32 delegate, uint8 proxyType, uint32 delay) external;
+//! }
+//! ```
+
+use scale_codec::Decode;
+use frame_support::dispatch::{Dispatchable, GetDispatchInfo, PostDispatchInfo};
+use frame_system::RawOrigin;
+use pallet_evm::{AddressMapping, Precompile, PrecompileHandle, PrecompileResult};
+use sp_runtime::traits::StaticLookup;
+use sp_std::{marker::PhantomData, prelude::*};
+
+use super::utils::{decode_call, dispatch, revert, Reader};
+
+/// `bytes4(keccak256("proxy(bytes32,bytes)"))`
+pub const PROXY_SELECTOR: [u8; 4] = [0xc0, 0x9c, 0x1b, 0x23];
+/// `bytes4(keccak256("addProxy(bytes32,uint8,uint32)"))`
+pub const ADD_PROXY_SELECTOR: [u8; 4] = [0x0c, 0xad, 0xed, 0xa5];
+/// `bytes4(keccak256("removeProxy(bytes32,uint8,uint32)"))`
+pub const REMOVE_PROXY_SELECTOR: [u8; 4] = [0xcc, 0x4b, 0x0a, 0x1b];
+
+pub struct ProxyPrecompile<R>(PhantomData<R>);
+
+impl<R> Precompile for ProxyPrecompile<R>
+where
+	R: pallet_evm::Config + pallet_proxy::Config,
+	R::AccountId: From<[u8; 32]>,
+	R::BlockNumber: From<u32>,
+	<R as frame_system::Config>::RuntimeCall: Dispatchable<PostInfo = PostDispatchInfo>
+		+ GetDispatchInfo
+		+ From<pallet_proxy::Call<R>>,
+	<<R as frame_system::Config>::RuntimeCall as Dispatchable>::RuntimeOrigin:
+		From<RawOrigin<R::AccountId>>,
+	<R as pallet_proxy::Config>::RuntimeCall: Decode,
+{
+	fn execute(handle: &mut impl PrecompileHandle) -> PrecompileResult {
+		let who = R::AddressMapping::into_account_id(handle.context().caller);
+		let input = handle.input().to_vec();
+		let (selector, args) = Reader::new(&input)?;
+		let account = R::Lookup::unlookup(R::AccountId::from(args.bytes32(0)?));
+
+		let call = match selector {
+			PROXY_SELECTOR => pallet_proxy::Call::<R>::proxy {
+				real: account,
+				force_proxy_type: None,
+				call: Box::new(decode_call(&args.bytes(1)?)?),
+			},
+			ADD_PROXY_SELECTOR => pallet_proxy::Call::<R>::add_proxy {
+				delegate: account,
+				proxy_type: Self::proxy_type(args.u32(1)?)?,
+				delay: args.u32(2)?.into(),
+			},
+			REMOVE_PROXY_SELECTOR => pallet_proxy::Call::<R>::remove_proxy {
+				delegate: account,
+				proxy_type: Self::proxy_type(args.u32(1)?)?,
+				delay: args.u32(2)?.into(),
+			},
+			_ => return Err(revert("invalid selector")),
+		};
+
+		dispatch::<R>(handle, who, call)
+	}
+}
+
+impl<R: pallet_proxy::Config> ProxyPrecompile<R> {
+	fn proxy_type(index: u32) -> Result<R::ProxyType, pallet_evm::PrecompileFailure> {
+		let index = u8::try_from(index).map_err(|_| revert("invalid proxy type"))?;
+		R::ProxyType::decode(&mut &[index][..]).map_err(|_| revert("invalid proxy type"))
+	}
+}
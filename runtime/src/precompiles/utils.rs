@@ -0,0 +1,293 @@
+//! Helpers shared by the QChain precompiles: Solidity ABI decoding of call arguments and
+//! dispatching runtime calls on behalf of the EVM caller.
+
+use frame_support::dispatch::{Dispatchable, GetDispatchInfo, PostDispatchInfo};
+use frame_system::RawOrigin;
+use pallet_evm::{
+	ExitRevert, ExitSucceed, GasWeightMapping, PrecompileFailure, PrecompileHandle,
+	PrecompileOutput, PrecompileResult,
+};
+use scale_codec::{Decode, DecodeLimit};
+use sp_core::U256;
+use sp_std::prelude::*;
+
+/// Reads the ABI encoded arguments following the selector of a precompile call.
+pub struct Reader<'a> {
+	input: &'a [u8],
+}
+
+impl<'a> Reader<'a> {
+	/// Split `input` into its selector and the reader of its arguments.
+	pub fn new(input: &'a [u8]) -> Result<([u8; 4], Self), PrecompileFailure> {
+		if input.len() < 4 {
+			return Err(revert("invalid selector"))
+		}
+		let mut selector = [0u8; 4];
+		selector.copy_from_slice(&input[..4]);
+		Ok((selector, Self { input: &input[4..] }))
+	}
+
+	fn word_at(&self, offset: usize) -> Result<[u8; 32], PrecompileFailure> {
+		let mut word = [0u8; 32];
+		word.copy_from_slice(self.slice(offset, 32)?);
+		Ok(word)
+	}
+
+	fn slice(&self, offset: usize, len: usize) -> Result<&'a [u8], PrecompileFailure> {
+		offset
+			.checked_add(len)
+			.and_then(|end| self.input.get(offset..end))
+			.ok_or_else(|| revert("input too short"))
+	}
+
+	fn usize_at(&self, offset: usize) -> Result<usize, PrecompileFailure> {
+		let value = U256::from_big_endian(&self.word_at(offset)?);
+		if value > U256::from(u32::MAX) {
+			return Err(revert("offset too large"))
+		}
+		Ok(value.low_u64() as usize)
+	}
+
+	/// The `index`-th argument, of a static 32 bytes type.
+	pub fn bytes32(&self, index: usize) -> Result<[u8; 32], PrecompileFailure> {
+		self.word_at(index * 32)
+	}
+
+	/// The `index`-th argument, of an unsigned integer type of at most 32 bits.
+	pub fn u32(&self, index: usize) -> Result<u32, PrecompileFailure> {
+		let value = U256::from_big_endian(&self.bytes32(index)?);
+		if value > U256::from(u32::MAX) {
+			return Err(revert("value out of range"))
+		}
+		Ok(value.low_u32())
+	}
+
+	/// The `index`-th argument, of the dynamic `bytes` type.
+	pub fn bytes(&self, index: usize) -> Result<Vec<u8>, PrecompileFailure> {
+		let offset = self.usize_at(index * 32)?;
+		let len = self.usize_at(offset)?;
+		let start = offset.checked_add(32).ok_or_else(|| revert("input too short"))?;
+		Ok(self.slice(start, len)?.to_vec())
+	}
+
+	/// The `index`-th argument, of the dynamic `bytes32[]` type.
+	pub fn bytes32_array(&self, index: usize) -> Result<Vec<[u8; 32]>, PrecompileFailure> {
+		let offset = self.usize_at(index * 32)?;
+		let len = self.usize_at(offset)?;
+		(1..=len)
+			.map(|i| {
+				let at = i.checked_mul(32).and_then(|at| at.checked_add(offset));
+				self.word_at(at.ok_or_else(|| revert("input too short"))?)
+			})
+			.collect()
+	}
+}
+
+/// Dispatch `call` signed by `who`, charging its weight as gas up front. The call goes through
+/// the runtime call filter, like any extrinsic.
+///
+/// Precompiles dispatch on behalf of their caller, so they cannot be reached with DELEGATECALL:
+/// the caller would then be the one of the contract delegating the call, which could act for
+/// whoever called it.
+pub fn dispatch<R>(
+	handle: &mut impl PrecompileHandle,
+	who: R::AccountId,
+	call: impl Into<<R as frame_system::Config>::RuntimeCall>,
+) -> PrecompileResult
+where
+	R: pallet_evm::Config,
+	<R as frame_system::Config>::RuntimeCall:
+		Dispatchable<PostInfo = PostDispatchInfo> + GetDispatchInfo,
+	<<R as frame_system::Config>::RuntimeCall as Dispatchable>::RuntimeOrigin:
+		From<RawOrigin<R::AccountId>>,
+{
+	if handle.code_address() != handle.context().address {
+		return Err(revert("cannot be called with DELEGATECALL"))
+	}
+	if handle.is_static() {
+		return Err(revert("cannot dispatch in a static call"))
+	}
+
+	let call = call.into();
+	let weight = call.get_dispatch_info().weight;
+	handle.record_cost(R::GasWeightMapping::weight_to_gas(weight))?;
+
+	call.dispatch(RawOrigin::Signed(who).into()).map_err(|e| revert(e.error.into()))?;
+	Ok(PrecompileOutput { exit_status: ExitSucceed::Returned, output: vec![] })
+}
+
+/// Decode a SCALE encoded runtime call given by the caller, with the nesting limit of the calls
+/// of extrinsics.
+pub fn decode_call<C: Decode>(encoded: &[u8]) -> Result<C, PrecompileFailure> {
+	C::decode_with_depth_limit(sp_api::MAX_EXTRINSIC_DEPTH, &mut &encoded[..])
+		.map_err(|_| revert("invalid call"))
+}
+
+pub fn revert(reason: &'static str) -> PrecompileFailure {
+	PrecompileFailure::Revert { exit_status: ExitRevert::Reverted, output: reason.as_bytes().to_vec() }
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::{precompiles::proxy::*, Runtime};
+	use fp_evm::{Context, ExitError, ExitReason, Precompile, Transfer};
+	use scale_codec::Encode;
+	use sp_core::{H160, H256};
+
+	fn word(n: u64) -> Vec<u8> {
+		let mut word = [0u8; 32];
+		U256::from(n).to_big_endian(&mut word);
+		word.to_vec()
+	}
+
+	/// The handle of a precompile at `code_address` executed in `context`.
+	struct MockHandle {
+		code_address: H160,
+		context: Context,
+		input: Vec<u8>,
+	}
+
+	impl PrecompileHandle for MockHandle {
+		fn call(
+			&mut self,
+			_: H160,
+			_: Option<Transfer>,
+			_: Vec<u8>,
+			_: Option<u64>,
+			_: bool,
+			_: &Context,
+		) -> (ExitReason, Vec<u8>) {
+			unimplemented!("the precompiles make no subcalls")
+		}
+
+		fn record_cost(&mut self, _: u64) -> Result<(), ExitError> {
+			Ok(())
+		}
+
+		fn remaining_gas(&self) -> u64 {
+			u64::MAX
+		}
+
+		fn log(&mut self, _: H160, _: Vec<H256>, _: Vec<u8>) -> Result<(), ExitError> {
+			Ok(())
+		}
+
+		fn code_address(&self) -> H160 {
+			self.code_address
+		}
+
+		fn input(&self) -> &[u8] {
+			&self.input
+		}
+
+		fn context(&self) -> &Context {
+			&self.context
+		}
+
+		fn is_static(&self) -> bool {
+			false
+		}
+
+		fn gas_limit(&self) -> Option<u64> {
+			None
+		}
+	}
+
+	#[test]
+	fn decode_call_limits_nesting() {
+		let nested = |depth| {
+			(0..depth).fold(
+				crate::RuntimeCall::System(frame_system::Call::remark { remark: vec![] }),
+				|call, _| {
+					crate::RuntimeCall::Utility(pallet_utility::Call::batch { calls: vec![call] })
+				},
+			)
+		};
+
+		let call = nested(10);
+		assert_eq!(decode_call::<crate::RuntimeCall>(&call.encode()).ok(), Some(call));
+		assert!(decode_call::<crate::RuntimeCall>(&nested(300).encode()).is_err());
+	}
+
+	#[test]
+	fn dispatch_rejects_delegate_calls() {
+		let precompile = H160::from_low_u64_be(2052);
+		let victim = H160::repeat_byte(0xaa);
+		let contract = H160::repeat_byte(0xbb);
+		// `addProxy(attacker, Any, 0)`
+		let input = [ADD_PROXY_SELECTOR.to_vec(), [0xcc; 32].to_vec(), word(0), word(0)].concat();
+
+		sp_io::TestExternalities::default().execute_with(|| {
+			// the victim calls a contract delegating the call to the precompile
+			let mut handle = MockHandle {
+				code_address: precompile,
+				context: Context {
+					address: contract,
+					caller: victim,
+					apparent_value: U256::zero(),
+				},
+				input,
+			};
+			assert!(matches!(
+				ProxyPrecompile::<Runtime>::execute(&mut handle),
+				Err(PrecompileFailure::Revert { output, .. })
+					if output == b"cannot be called with DELEGATECALL".to_vec()
+			));
+
+			// calling the precompile itself dispatches
+			let mut handle = MockHandle {
+				code_address: precompile,
+				context: Context {
+					address: precompile,
+					caller: victim,
+					apparent_value: U256::zero(),
+				},
+				input: vec![],
+			};
+			assert!(dispatch::<Runtime>(
+				&mut handle,
+				[0xaa; 32].into(),
+				frame_system::Call::<Runtime>::remark { remark: vec![] },
+			)
+			.is_ok());
+		});
+	}
+
+	#[test]
+	fn reads_dynamic_arguments() {
+		// f(uint16 7, bytes32[] [0x11.., 0x22..], bytes 0xabcd)
+		let input = [
+			vec![1, 2, 3, 4],
+			word(7),
+			word(96),
+			word(192),
+			word(2),
+			[0x11; 32].to_vec(),
+			[0x22; 32].to_vec(),
+			word(2),
+			[[0xab, 0xcd].to_vec(), vec![0; 30]].concat(),
+		]
+		.concat();
+
+		let (selector, reader) = Reader::new(&input).unwrap();
+		assert_eq!(selector, [1, 2, 3, 4]);
+		assert_eq!(reader.u32(0).unwrap(), 7);
+		assert_eq!(reader.bytes32_array(1).unwrap(), vec![[0x11; 32], [0x22; 32]]);
+		assert_eq!(reader.bytes(2).unwrap(), vec![0xab, 0xcd]);
+		assert!(reader.bytes32(9).is_err());
+	}
+
+	#[test]
+	fn rejects_out_of_range_offsets_and_lengths() {
+		let max = u64::from(u32::MAX);
+		// f(bytes, bytes32[]) with a huge length, then a huge offset
+		let input = [vec![1, 2, 3, 4], word(64), word(max), word(max)].concat();
+
+		let (_, reader) = Reader::new(&input).unwrap();
+		assert!(reader.bytes(0).is_err());
+		assert!(reader.bytes32_array(0).is_err());
+		assert!(reader.bytes(1).is_err());
+		assert!(reader.bytes32_array(1).is_err());
+	}
+}
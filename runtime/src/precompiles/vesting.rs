@@ -0,0 +1,78 @@
+//! Access to `pallet_vesting` for EVM accounts, following the Solidity ABI of
+//!
+//! ```solidity
+//! interface Vesting {
+//!     // Unlock the funds of the caller that have vested so far.
+//!     function vest() external;
+//!     // Amount of the vesting schedules of the caller that has vested so far, unlocked or not.
+//!     function vestedBalance() external view returns (uint256);
+//! }
+//! ```
+
+use frame_support::traits::{Get, VestingSchedule};
+use frame_system::RawOrigin;
+use pallet_evm::{
+	AddressMapping, ExitSucceed, GasWeightMapping, Precompile, PrecompileHandle, PrecompileOutput,
+	PrecompileResult,
+};
+use pallet_vesting::WeightInfo;
+use sp_arithmetic::traits::UniqueSaturatedInto;
+use sp_core::U256;
+use sp_std::{marker::PhantomData, prelude::*};
+
+use super::utils::revert;
+
+/// `bytes4(keccak256("vest()"))`
+pub const VEST_SELECTOR: [u8; 4] = [0x45, 0x8e, 0xfd, 0xe3];
+/// `bytes4(keccak256("vestedBalance()"))`
+pub const VESTED_BALANCE_SELECTOR: [u8; 4] = [0x01, 0x6a, 0x0a, 0x4d];
+
+pub struct VestingPrecompile<R>(PhantomData<R>);
+
+impl<R> Precompile for VestingPrecompile<R>
+where
+	R: pallet_evm::Config + pallet_vesting::Config + pallet_balances::Config,
+{
+	fn execute(handle: &mut impl PrecompileHandle) -> PrecompileResult {
+		let who = R::AddressMapping::into_account_id(handle.context().caller);
+
+		match handle.input().get(..4) {
+			Some(selector) if selector == VEST_SELECTOR => {
+				if handle.is_static() {
+					return Err(revert("cannot vest in a static call"))
+				}
+				let weight = <R as pallet_vesting::Config>::WeightInfo::vest_locked(
+					<R as pallet_balances::Config>::MaxLocks::get(),
+					R::MAX_VESTING_SCHEDULES,
+				);
+				handle.record_cost(R::GasWeightMapping::weight_to_gas(weight))?;
+
+				pallet_vesting::Pallet::<R>::vest(RawOrigin::Signed(who).into())
+					.map_err(|e| revert(e.into()))?;
+
+				Ok(PrecompileOutput { exit_status: ExitSucceed::Returned, output: vec![] })
+			},
+			Some(selector) if selector == VESTED_BALANCE_SELECTOR => {
+				let weight = <R as frame_system::Config>::DbWeight::get().reads(1);
+				handle.record_cost(R::GasWeightMapping::weight_to_gas(weight))?;
+
+				let total: u128 = pallet_vesting::Pallet::<R>::vesting(&who)
+					.unwrap_or_default()
+					.iter()
+					.map(|schedule| -> u128 { schedule.locked().unique_saturated_into() })
+					.fold(0, u128::saturating_add);
+				let locked: u128 =
+					<pallet_vesting::Pallet<R> as VestingSchedule<R::AccountId>>::vesting_balance(
+						&who,
+					)
+					.map(UniqueSaturatedInto::unique_saturated_into)
+					.unwrap_or_default();
+				let mut output = [0u8; 32];
+				U256::from(total.saturating_sub(locked)).to_big_endian(&mut output);
+
+				Ok(PrecompileOutput { exit_status: ExitSucceed::Returned, output: output.to_vec() })
+			},
+			_ => Err(revert("invalid selector")),
+		}
+	}
+}
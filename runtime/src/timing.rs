@@ -0,0 +1,59 @@
+//! Consistency of the timing constants of the runtime.
+//!
+//! The checks are run by the `integrity_test` hook of [`pallet`], a pallet without storage nor
+//! calls, so that the integrity test generated by `construct_runtime` covers them along with
+//! the checks of every other pallet.
+
+use frame_support::{pallet_prelude::Get, traits::EstimateNextSessionRotation};
+
+use crate::{
+	chain_timing, constants::time::*, Runtime, SignedPhase, UnsignedPhase,
+	BABE_GENESIS_EPOCH_CONFIG, WEIGHT_MILLISECS_PER_BLOCK,
+};
+
+/// Assert that the block, slot, epoch, session and era durations agree with each other.
+pub fn integrity_test() {
+	let timing = chain_timing();
+
+	// Every slot is assigned with secondary slots, so blocks are a slot apart.
+	assert_eq!(
+		BABE_GENESIS_EPOCH_CONFIG.allowed_slots,
+		sp_consensus_babe::AllowedSlots::PrimaryAndSecondaryPlainSlots
+	);
+	assert_eq!(timing.block_time, MILLISECS_PER_BLOCK);
+	assert_eq!(timing.slot_duration, SLOT_DURATION);
+	assert_eq!(timing.block_time, timing.slot_duration);
+	assert_eq!(timing.epoch_duration, EPOCH_DURATION_IN_SLOTS);
+	assert_eq!(timing.epoch_duration, EPOCH_DURATION_IN_BLOCKS as u64);
+	assert_eq!(timing.sessions_per_era, SESSIONS_PER_ERA);
+	assert_eq!(timing.era_duration, ERA_DURATION);
+
+	// Blocks must leave time to propagate once they are built.
+	assert!(WEIGHT_MILLISECS_PER_BLOCK < MILLISECS_PER_BLOCK);
+	// The election of the next era must fit in the last session of an era.
+	assert!(SignedPhase::get() + UnsignedPhase::get() < EPOCH_DURATION_IN_BLOCKS);
+	// Sessions are counted in BABE epochs.
+	assert_eq!(
+		<Runtime as pallet_session::Config>::NextSessionRotation::average_session_length(),
+		EPOCH_DURATION_IN_BLOCKS
+	);
+}
+
+#[frame_support::pallet]
+pub mod pallet {
+	use frame_support::pallet_prelude::*;
+	use frame_system::pallet_prelude::*;
+
+	#[pallet::pallet]
+	pub struct Pallet<T>(_);
+
+	#[pallet::config]
+	pub trait Config: frame_system::Config {}
+
+	#[pallet::hooks]
+	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+		fn integrity_test() {
+			super::integrity_test()
+		}
+	}
+}